@@ -77,6 +77,7 @@ program
                 discountPercentage: jsonData.discountPercentage,
                 endTime: new BN(convertDateStringToUnixTimeSecond(jsonData.endTime)),
                 maximumAmount: 30,
+                maxUses: jsonData.maxUses ?? 1,
                 startTime: new BN(convertDateStringToUnixTimeSecond(jsonData.startTime)),
             }
         );
@@ -93,14 +94,16 @@ program
     .requiredOption('--seed <string>', 'Seed of the vault')
     .requiredOption('--mint <string>', 'Address of the user who receives the airdrop')
     .requiredOption('--user <string>', 'Mint address of the nft')
+    .option('--user_keypair <string>', 'Keypair path of the user, required when the vault is soulbound')
     .action(async (params) => {
         console.log('Params', params);
-        let { network, source, program_id, seed, user, mint } = params;
+        let { network, source, program_id, seed, user, mint, user_keypair } = params;
         const operator = await getKeypairFromFile(source);
         const mintAddress = new PublicKey(mint);
         const userAddress = new PublicKey(user);
+        const userSigner = user_keypair ? await getKeypairFromFile(user_keypair) : undefined;
         const fixture = await buildFixture(network, source, program_id);
-        const tx = await fixture.operatorAirdrop(seed, operator, mintAddress, userAddress);
+        const tx = await fixture.operatorAirdrop(seed, operator, mintAddress, userAddress, userSigner);
 
         console.log(`Airdrop mint ${mint} to user ${user} success at ${tx}`);
     });
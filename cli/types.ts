@@ -3,6 +3,7 @@ export type RepayVoucherInformation = {
     endTime: string;
     discountPercentage: number;
     maximumAmount: number;
+    maxUses?: number;
     images: string;
 } & MetadataInformation;
 
@@ -0,0 +1,98 @@
+use crate::error::{Error, Result};
+use crate::pda::Pda;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+use voucher_nft::{
+    ClaimDistributor, Config, FixedCreditVoucher, InterestRateVoucher, OperatorRole, RepayVoucher,
+    Vault,
+};
+
+pub fn decode_account<T: AccountDeserialize>(address: &Pubkey, data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..]).map_err(|err| Error::Decode(*address, err))
+}
+
+pub fn fetch_account<T: AccountDeserialize>(client: &RpcClient, address: &Pubkey) -> Result<T> {
+    let account = client
+        .get_account_with_commitment(address, client.commitment())?
+        .value
+        .ok_or(Error::AccountNotFound(*address))?;
+    decode_account(address, &account.data)
+}
+
+// Fetches every account of type `T` owned by the program, filtered on the anchor discriminator
+pub fn fetch_all_accounts<T: AccountDeserialize + Discriminator>(
+    client: &RpcClient,
+    program_id: &Pubkey,
+) -> Result<Vec<(Pubkey, T)>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp {
+            offset: 0,
+            bytes: MemcmpEncodedBytes::Base58(bs58::encode(T::discriminator()).into_string()),
+            encoding: None,
+        })]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        with_context: None,
+    };
+    client
+        .get_program_accounts_with_config(program_id, config)?
+        .into_iter()
+        .map(|(address, account)| Ok((address, decode_account(&address, &account.data)?)))
+        .collect()
+}
+
+pub fn fetch_config(client: &RpcClient, pda: &Pda) -> Result<Config> {
+    fetch_account(client, &pda.config().0)
+}
+
+pub fn fetch_vault(client: &RpcClient, vault: &Pubkey) -> Result<Vault> {
+    fetch_account(client, vault)
+}
+
+pub fn fetch_vaults(client: &RpcClient, pda: &Pda) -> Result<Vec<(Pubkey, Vault)>> {
+    fetch_all_accounts(client, &pda.program_id)
+}
+
+pub fn fetch_operator_role(
+    client: &RpcClient,
+    pda: &Pda,
+    vault: &Pubkey,
+    operator: &Pubkey,
+) -> Result<OperatorRole> {
+    fetch_account(client, &pda.operator_role(vault, operator).0)
+}
+
+pub fn fetch_repay_voucher(client: &RpcClient, pda: &Pda, mint: &Pubkey) -> Result<RepayVoucher> {
+    fetch_account(client, &pda.repay_voucher(mint).0)
+}
+
+pub fn fetch_interest_rate_voucher(
+    client: &RpcClient,
+    pda: &Pda,
+    mint: &Pubkey,
+) -> Result<InterestRateVoucher> {
+    fetch_account(client, &pda.interest_rate_voucher(mint).0)
+}
+
+pub fn fetch_fixed_credit_voucher(
+    client: &RpcClient,
+    pda: &Pda,
+    mint: &Pubkey,
+) -> Result<FixedCreditVoucher> {
+    fetch_account(client, &pda.fixed_credit_voucher(mint).0)
+}
+
+pub fn fetch_claim_distributor(
+    client: &RpcClient,
+    pda: &Pda,
+    vault: &Pubkey,
+    merkle_root: &[u8; 32],
+) -> Result<ClaimDistributor> {
+    fetch_account(client, &pda.claim_distributor(vault, merkle_root).0)
+}
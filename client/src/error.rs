@@ -0,0 +1,14 @@
+use anchor_lang::prelude::{ProgramError, Pubkey};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("rpc request failed: {0}")]
+    Rpc(#[from] solana_client::client_error::ClientError),
+    #[error("account {0} not found")]
+    AccountNotFound(Pubkey),
+    #[error("failed to decode account {0}: {1}")]
+    Decode(Pubkey, ProgramError),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
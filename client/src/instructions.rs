@@ -0,0 +1,749 @@
+use crate::pda::Pda;
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use solana_sdk::ed25519_instruction::new_ed25519_instruction;
+use solana_sdk::signature::Keypair;
+use voucher_nft::constants::TOKEN_METADATA_PROGRAM_ID;
+use voucher_nft::{accounts, instruction};
+use voucher_nft::{
+    AddFixedCreditVoucherParams, AddInterestRateVoucherParams, AddRepayVoucherParams,
+    ApplyRepayDiscountParams, ClaimTicket, MetadataParams, SetPauseParams,
+    UpdateRepayVoucherParams, VoucherKind,
+};
+
+fn build(pda: &Pda, accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: pda.program_id,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// Soulbound vaults freeze the voucher in the recipient token account, which the
+// recipient approves by co-signing, so mark it signer on the airdrop instruction
+pub fn with_signer(mut ix: Instruction, signer: &Pubkey) -> Instruction {
+    for meta in ix.accounts.iter_mut() {
+        if meta.pubkey == *signer {
+            meta.is_signer = true;
+        }
+    }
+    ix
+}
+
+pub fn initialize(
+    pda: &Pda,
+    admin: &Pubkey,
+    relend_collection: &Pubkey,
+    params: MetadataParams,
+) -> Instruction {
+    let authorator = pda.authorator().0;
+    build(
+        pda,
+        accounts::Initialize {
+            config: pda.config().0,
+            authorator,
+            relend_collection: *relend_collection,
+            authorator_token_account: get_associated_token_address(&authorator, relend_collection),
+            metadata_account: pda.metadata(relend_collection).0,
+            master_edition: pda.master_edition(relend_collection).0,
+            admin: *admin,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::Initialize { params },
+    )
+}
+
+pub fn propose_admin(pda: &Pda, admin: &Pubkey, pending_admin: &Pubkey) -> Instruction {
+    build(
+        pda,
+        accounts::ProposeAdmin {
+            config: pda.config().0,
+            admin: *admin,
+        },
+        instruction::ProposeAdmin {
+            pending_admin: *pending_admin,
+        },
+    )
+}
+
+pub fn accept_admin(pda: &Pda, pending_admin: &Pubkey) -> Instruction {
+    build(
+        pda,
+        accounts::AcceptAdmin {
+            config: pda.config().0,
+            pending_admin: *pending_admin,
+        },
+        instruction::AcceptAdmin {},
+    )
+}
+
+pub fn set_pause(pda: &Pda, admin: &Pubkey, params: SetPauseParams) -> Instruction {
+    build(
+        pda,
+        accounts::SetPause {
+            config: pda.config().0,
+            admin: *admin,
+        },
+        instruction::SetPause { params },
+    )
+}
+
+pub fn add_vault(pda: &Pda, admin: &Pubkey, seed: &str, operator: &Pubkey) -> Instruction {
+    build(
+        pda,
+        accounts::AddVault {
+            config: pda.config().0,
+            vault: pda.vault(seed).0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::AddVault {
+            seed: seed.to_string(),
+            operator: *operator,
+        },
+    )
+}
+
+pub fn set_vault_operator(
+    pda: &Pda,
+    admin: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+) -> Instruction {
+    build(
+        pda,
+        accounts::SetVaultOperator {
+            config: pda.config().0,
+            vault: *vault,
+            admin: *admin,
+        },
+        instruction::SetVaultOperator {
+            operator: *operator,
+        },
+    )
+}
+
+pub fn set_vault_soulbound(
+    pda: &Pda,
+    admin: &Pubkey,
+    vault: &Pubkey,
+    soulbound: bool,
+) -> Instruction {
+    build(
+        pda,
+        accounts::SetVaultSoulbound {
+            config: pda.config().0,
+            vault: *vault,
+            admin: *admin,
+        },
+        instruction::SetVaultSoulbound { soulbound },
+    )
+}
+
+pub fn close_vault(pda: &Pda, admin: &Pubkey, vault: &Pubkey) -> Instruction {
+    build(
+        pda,
+        accounts::CloseVault {
+            config: pda.config().0,
+            vault: *vault,
+            admin: *admin,
+        },
+        instruction::CloseVault {},
+    )
+}
+
+pub fn grant_operator_role(
+    pda: &Pda,
+    admin: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    permissions: u8,
+) -> Instruction {
+    build(
+        pda,
+        accounts::GrantOperatorRole {
+            config: pda.config().0,
+            vault: *vault,
+            operator_role: pda.operator_role(vault, operator).0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::GrantOperatorRole {
+            operator: *operator,
+            permissions,
+        },
+    )
+}
+
+pub fn revoke_operator_role(
+    pda: &Pda,
+    admin: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    permissions: u8,
+) -> Instruction {
+    build(
+        pda,
+        accounts::RevokeOperatorRole {
+            config: pda.config().0,
+            vault: *vault,
+            operator_role: pda.operator_role(vault, operator).0,
+            admin: *admin,
+        },
+        instruction::RevokeOperatorRole { permissions },
+    )
+}
+
+pub fn mint_voucher(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    collection_mint: &Pubkey,
+    params: MetadataParams,
+) -> Instruction {
+    build(
+        pda,
+        accounts::MintVoucher {
+            config: pda.config().0,
+            vault: *vault,
+            authorator: pda.authorator().0,
+            operator: *operator,
+            operator_role: pda.operator_role(vault, operator).0,
+            mint: *mint,
+            vault_token_account: get_associated_token_address(vault, mint),
+            metadata_account: pda.metadata(mint).0,
+            master_edition: pda.master_edition(mint).0,
+            collection_mint: *collection_mint,
+            collection_metadata_account: pda.metadata(collection_mint).0,
+            collection_master_edition: pda.master_edition(collection_mint).0,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            system_program: system_program::ID,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::MintVoucher { params },
+    )
+}
+
+pub fn mint_voucher_to(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+    collection_mint: &Pubkey,
+    params: MetadataParams,
+) -> Instruction {
+    build(
+        pda,
+        accounts::MintVoucherTo {
+            config: pda.config().0,
+            vault: *vault,
+            authorator: pda.authorator().0,
+            operator: *operator,
+            operator_role: pda.operator_role(vault, operator).0,
+            mint: *mint,
+            recipient: *recipient,
+            recipient_token_account: get_associated_token_address(recipient, mint),
+            metadata_account: pda.metadata(mint).0,
+            master_edition: pda.master_edition(mint).0,
+            collection_mint: *collection_mint,
+            collection_metadata_account: pda.metadata(collection_mint).0,
+            collection_master_edition: pda.master_edition(collection_mint).0,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            system_program: system_program::ID,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::MintVoucherTo { params },
+    )
+}
+
+pub fn mint_repay_voucher(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    collection_mint: &Pubkey,
+    metadata_params: MetadataParams,
+    repay_voucher_params: AddRepayVoucherParams,
+) -> Instruction {
+    build(
+        pda,
+        accounts::MintRepayVoucher {
+            config: pda.config().0,
+            vault: *vault,
+            authorator: pda.authorator().0,
+            operator: *operator,
+            operator_role: pda.operator_role(vault, operator).0,
+            mint: *mint,
+            vault_token_account: get_associated_token_address(vault, mint),
+            metadata_account: pda.metadata(mint).0,
+            master_edition: pda.master_edition(mint).0,
+            collection_mint: *collection_mint,
+            collection_metadata_account: pda.metadata(collection_mint).0,
+            collection_master_edition: pda.master_edition(collection_mint).0,
+            repay_voucher: pda.repay_voucher(mint).0,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            system_program: system_program::ID,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::MintRepayVoucher {
+            metadata_params,
+            repay_voucher_params,
+        },
+    )
+}
+
+pub fn add_voucher_repay_information(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    params: AddRepayVoucherParams,
+) -> Instruction {
+    build(
+        pda,
+        accounts::AddRepayVoucher {
+            config: pda.config().0,
+            vault: *vault,
+            operator: *operator,
+            operator_role: pda.operator_role(vault, operator).0,
+            mint: *mint,
+            metadata_account: pda.metadata(mint).0,
+            master_edition: pda.master_edition(mint).0,
+            repay_voucher: pda.repay_voucher(mint).0,
+            interest_rate_voucher: pda.interest_rate_voucher(mint).0,
+            fixed_credit_voucher: pda.fixed_credit_voucher(mint).0,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        instruction::AddVoucherRepayInformation { params },
+    )
+}
+
+pub fn add_voucher_interest_information(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    params: AddInterestRateVoucherParams,
+) -> Instruction {
+    build(
+        pda,
+        accounts::AddInterestRateVoucher {
+            config: pda.config().0,
+            vault: *vault,
+            operator: *operator,
+            operator_role: pda.operator_role(vault, operator).0,
+            mint: *mint,
+            metadata_account: pda.metadata(mint).0,
+            master_edition: pda.master_edition(mint).0,
+            interest_rate_voucher: pda.interest_rate_voucher(mint).0,
+            repay_voucher: pda.repay_voucher(mint).0,
+            fixed_credit_voucher: pda.fixed_credit_voucher(mint).0,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        instruction::AddVoucherInterestInformation { params },
+    )
+}
+
+pub fn add_voucher_fixed_credit_information(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    currency_mint: &Pubkey,
+    params: AddFixedCreditVoucherParams,
+) -> Instruction {
+    build(
+        pda,
+        accounts::AddFixedCreditVoucher {
+            config: pda.config().0,
+            vault: *vault,
+            operator: *operator,
+            operator_role: pda.operator_role(vault, operator).0,
+            mint: *mint,
+            metadata_account: pda.metadata(mint).0,
+            master_edition: pda.master_edition(mint).0,
+            currency_mint: *currency_mint,
+            fixed_credit_voucher: pda.fixed_credit_voucher(mint).0,
+            repay_voucher: pda.repay_voucher(mint).0,
+            interest_rate_voucher: pda.interest_rate_voucher(mint).0,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        instruction::AddVoucherFixedCreditInformation { params },
+    )
+}
+
+pub fn update_repay_voucher(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    params: UpdateRepayVoucherParams,
+) -> Instruction {
+    build(
+        pda,
+        accounts::UpdateRepayVoucher {
+            vault: *vault,
+            operator: *operator,
+            operator_role: pda.operator_role(vault, operator).0,
+            metadata_account: pda.metadata(mint).0,
+            repay_voucher: pda.repay_voucher(mint).0,
+        },
+        instruction::UpdateRepayVoucher { params },
+    )
+}
+
+pub fn close_repay_voucher(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    burn_vault_voucher: bool,
+) -> Instruction {
+    build(
+        pda,
+        accounts::CloseRepayVoucher {
+            vault: *vault,
+            operator: *operator,
+            operator_role: pda.operator_role(vault, operator).0,
+            mint: *mint,
+            vault_token_account: get_associated_token_address(vault, mint),
+            metadata_account: pda.metadata(mint).0,
+            repay_voucher: pda.repay_voucher(mint).0,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::CloseRepayVoucher { burn_vault_voucher },
+    )
+}
+
+pub fn operator_airdrop(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    user: &Pubkey,
+) -> Instruction {
+    build(
+        pda,
+        accounts::OperatorAirdrop {
+            config: pda.config().0,
+            vault: *vault,
+            operator: *operator,
+            operator_role: pda.operator_role(vault, operator).0,
+            user: *user,
+            mint: *mint,
+            master_edition: pda.master_edition(mint).0,
+            vault_token_account: get_associated_token_address(vault, mint),
+            user_token_account: get_associated_token_address(user, mint),
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::OperatorAirdrop {},
+    )
+}
+
+// Each `(mint, user)` pair is appended as the five remaining accounts the program expects
+pub fn batch_operator_airdrop(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    airdrops: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    let mut ix = build(
+        pda,
+        accounts::BatchOperatorAirdrop {
+            config: pda.config().0,
+            vault: *vault,
+            operator: *operator,
+            operator_role: pda.operator_role(vault, operator).0,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::BatchOperatorAirdrop {},
+    );
+    for (mint, user) in airdrops {
+        ix.accounts.extend([
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(pda.master_edition(mint).0, false),
+            AccountMeta::new(get_associated_token_address(vault, mint), false),
+            AccountMeta::new_readonly(*user, false),
+            AccountMeta::new(get_associated_token_address(user, mint), false),
+        ]);
+    }
+    ix
+}
+
+pub fn get_interest_rate_reduction(pda: &Pda, holder: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        pda,
+        accounts::GetInterestRateReduction {
+            config: pda.config().0,
+            holder: *holder,
+            mint: *mint,
+            holder_token_account: get_associated_token_address(holder, mint),
+            interest_rate_voucher: pda.interest_rate_voucher(mint).0,
+        },
+        instruction::GetInterestRateReduction {},
+    )
+}
+
+fn revoke_voucher_accounts(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    kind: VoucherKind,
+    mint: &Pubkey,
+    holder_token_account: &Pubkey,
+) -> accounts::RevokeVoucher {
+    accounts::RevokeVoucher {
+        vault: *vault,
+        operator: *operator,
+        operator_role: pda.operator_role(vault, operator).0,
+        mint: *mint,
+        holder_token_account: *holder_token_account,
+        metadata_account: pda.metadata(mint).0,
+        voucher_information: pda.voucher_information(kind, mint).0,
+    }
+}
+
+pub fn revoke_voucher(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    kind: VoucherKind,
+    mint: &Pubkey,
+    holder_token_account: &Pubkey,
+) -> Instruction {
+    build(
+        pda,
+        revoke_voucher_accounts(pda, operator, vault, kind, mint, holder_token_account),
+        instruction::RevokeVoucher {},
+    )
+}
+
+pub fn unrevoke_voucher(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    kind: VoucherKind,
+    mint: &Pubkey,
+    holder_token_account: &Pubkey,
+) -> Instruction {
+    build(
+        pda,
+        revoke_voucher_accounts(pda, operator, vault, kind, mint, holder_token_account),
+        instruction::UnrevokeVoucher {},
+    )
+}
+
+pub fn create_claim_distributor(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    merkle_root: [u8; 32],
+    end_time: i64,
+) -> Instruction {
+    build(
+        pda,
+        accounts::CreateClaimDistributor {
+            config: pda.config().0,
+            vault: *vault,
+            operator: *operator,
+            operator_role: pda.operator_role(vault, operator).0,
+            distributor: pda.claim_distributor(vault, &merkle_root).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateClaimDistributor {
+            merkle_root,
+            end_time,
+        },
+    )
+}
+
+pub fn claim_voucher(
+    pda: &Pda,
+    user: &Pubkey,
+    vault: &Pubkey,
+    merkle_root: &[u8; 32],
+    mint: &Pubkey,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    let distributor = pda.claim_distributor(vault, merkle_root).0;
+    build(
+        pda,
+        accounts::ClaimVoucher {
+            config: pda.config().0,
+            vault: *vault,
+            distributor,
+            user: *user,
+            mint: *mint,
+            master_edition: pda.master_edition(mint).0,
+            vault_token_account: get_associated_token_address(vault, mint),
+            user_token_account: get_associated_token_address(user, mint),
+            claim_status: pda.claim_status(&distributor, mint).0,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::ClaimVoucher { proof },
+    )
+}
+
+// The ed25519 program instruction that has to come right before `claim_with_ticket`
+pub fn claim_ticket_signature(
+    pda: &Pda,
+    ticket_signer: &Keypair,
+    ticket: &ClaimTicket,
+) -> Instruction {
+    let keypair = ed25519_dalek::Keypair::from_bytes(&ticket_signer.to_bytes())
+        .expect("a keypair always holds valid ed25519 bytes");
+    new_ed25519_instruction(&keypair, &ticket.message(&pda.program_id))
+}
+
+// Must be preceded by `claim_ticket_signature` for the same ticket
+pub fn claim_with_ticket(pda: &Pda, ticket_signer: &Pubkey, ticket: ClaimTicket) -> Instruction {
+    build(
+        pda,
+        accounts::ClaimWithTicket {
+            config: pda.config().0,
+            vault: ticket.vault,
+            ticket_signer: *ticket_signer,
+            operator_role: pda.operator_role(&ticket.vault, ticket_signer).0,
+            recipient: ticket.recipient,
+            mint: ticket.mint,
+            master_edition: pda.master_edition(&ticket.mint).0,
+            vault_token_account: get_associated_token_address(&ticket.vault, &ticket.mint),
+            recipient_token_account: get_associated_token_address(&ticket.recipient, &ticket.mint),
+            ticket_nonce: pda.ticket_nonce(&ticket.vault, ticket.nonce).0,
+            instructions_sysvar: sysvar::instructions::ID,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::ClaimWithTicket { ticket },
+    )
+}
+
+// `payer` is the rent payer stored on the distributor as `ClaimDistributor::payer`
+pub fn clawback(
+    pda: &Pda,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    merkle_root: &[u8; 32],
+    payer: &Pubkey,
+) -> Instruction {
+    build(
+        pda,
+        accounts::Clawback {
+            vault: *vault,
+            operator: *operator,
+            operator_role: pda.operator_role(vault, operator).0,
+            distributor: pda.claim_distributor(vault, merkle_root).0,
+            payer: *payer,
+        },
+        instruction::Clawback {},
+    )
+}
+
+pub fn redeem_repay_voucher(
+    pda: &Pda,
+    holder: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        pda,
+        accounts::RedeemRepayVoucher {
+            config: pda.config().0,
+            vault: *vault,
+            holder: *holder,
+            mint: *mint,
+            holder_token_account: get_associated_token_address(holder, mint),
+            repay_voucher: pda.repay_voucher(mint).0,
+            metadata_account: pda.metadata(mint).0,
+            master_edition: pda.master_edition(mint).0,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::RedeemRepayVoucher { amount },
+    )
+}
+
+pub fn redeem_fixed_credit_voucher(
+    pda: &Pda,
+    holder: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    currency_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        pda,
+        accounts::RedeemFixedCreditVoucher {
+            config: pda.config().0,
+            vault: *vault,
+            holder: *holder,
+            mint: *mint,
+            holder_token_account: get_associated_token_address(holder, mint),
+            fixed_credit_voucher: pda.fixed_credit_voucher(mint).0,
+            currency_mint: *currency_mint,
+            master_edition: pda.master_edition(mint).0,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::RedeemFixedCreditVoucher { amount },
+    )
+}
+
+pub fn apply_repay_discount(
+    pda: &Pda,
+    holder: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    params: ApplyRepayDiscountParams,
+) -> Instruction {
+    build(
+        pda,
+        accounts::ApplyRepayDiscount {
+            config: pda.config().0,
+            vault: *vault,
+            holder: *holder,
+            mint: *mint,
+            holder_token_account: get_associated_token_address(holder, mint),
+            repay_voucher: pda.repay_voucher(mint).0,
+            metadata_account: pda.metadata(mint).0,
+            master_edition: pda.master_edition(mint).0,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::ApplyRepayDiscount { params },
+    )
+}
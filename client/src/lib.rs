@@ -0,0 +1,14 @@
+//! Off-chain client for the voucher-nft program.
+//!
+//! Builds on the program's own account and instruction types so that
+//! instruction data and account layouts can never drift from the program.
+
+pub mod accounts;
+pub mod error;
+pub mod instructions;
+pub mod pda;
+
+pub use error::{Error, Result};
+pub use pda::Pda;
+pub use voucher_nft;
+pub use voucher_nft::ID as PROGRAM_ID;
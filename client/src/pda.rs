@@ -0,0 +1,115 @@
+use anchor_lang::prelude::Pubkey;
+use voucher_nft::constants::{METADATA_EDITION, METADATA_PREFIX, TOKEN_METADATA_PROGRAM_ID};
+use voucher_nft::{
+    Authorator, ClaimDistributor, ClaimStatus, Config, FixedCreditVoucher, InterestRateVoucher,
+    OperatorRole, RepayVoucher, TicketNonce, Vault, VoucherKind,
+};
+
+// Derives the program addresses, mirroring `PDA` in the TypeScript sdk
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pda {
+    pub program_id: Pubkey,
+}
+
+impl Default for Pda {
+    fn default() -> Self {
+        Self::new(voucher_nft::ID)
+    }
+}
+
+impl Pda {
+    pub fn new(program_id: Pubkey) -> Self {
+        Self { program_id }
+    }
+
+    fn find(&self, seeds: &[&[u8]]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(seeds, &self.program_id)
+    }
+
+    pub fn config(&self) -> (Pubkey, u8) {
+        self.find(&[Config::SEED.as_bytes()])
+    }
+
+    pub fn authorator(&self) -> (Pubkey, u8) {
+        self.find(&[Authorator::SEED.as_bytes()])
+    }
+
+    pub fn vault(&self, seed: &str) -> (Pubkey, u8) {
+        self.find(&[Vault::SEED.as_bytes(), seed.as_bytes()])
+    }
+
+    pub fn operator_role(&self, vault: &Pubkey, operator: &Pubkey) -> (Pubkey, u8) {
+        self.find(&[
+            OperatorRole::SEED.as_bytes(),
+            vault.as_ref(),
+            operator.as_ref(),
+        ])
+    }
+
+    pub fn repay_voucher(&self, mint: &Pubkey) -> (Pubkey, u8) {
+        self.find(&[RepayVoucher::SEED.as_bytes(), mint.as_ref()])
+    }
+
+    pub fn interest_rate_voucher(&self, mint: &Pubkey) -> (Pubkey, u8) {
+        self.find(&[InterestRateVoucher::SEED.as_bytes(), mint.as_ref()])
+    }
+
+    pub fn fixed_credit_voucher(&self, mint: &Pubkey) -> (Pubkey, u8) {
+        self.find(&[FixedCreditVoucher::SEED.as_bytes(), mint.as_ref()])
+    }
+
+    pub fn voucher_information(&self, kind: VoucherKind, mint: &Pubkey) -> (Pubkey, u8) {
+        match kind {
+            VoucherKind::Repay => self.repay_voucher(mint),
+            VoucherKind::InterestRate => self.interest_rate_voucher(mint),
+            VoucherKind::FixedCredit => self.fixed_credit_voucher(mint),
+        }
+    }
+
+    pub fn claim_distributor(&self, vault: &Pubkey, merkle_root: &[u8; 32]) -> (Pubkey, u8) {
+        self.find(&[
+            ClaimDistributor::SEED.as_bytes(),
+            vault.as_ref(),
+            merkle_root.as_ref(),
+        ])
+    }
+
+    pub fn claim_status(&self, distributor: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+        self.find(&[
+            ClaimStatus::SEED.as_bytes(),
+            distributor.as_ref(),
+            mint.as_ref(),
+        ])
+    }
+
+    pub fn ticket_nonce(&self, vault: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+        self.find(&[
+            TicketNonce::SEED.as_bytes(),
+            vault.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ])
+    }
+
+    pub fn metadata(&self, mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                METADATA_PREFIX.as_bytes(),
+                TOKEN_METADATA_PROGRAM_ID.as_ref(),
+                mint.as_ref(),
+            ],
+            &TOKEN_METADATA_PROGRAM_ID,
+        )
+    }
+
+    pub fn master_edition(&self, mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                METADATA_PREFIX.as_bytes(),
+                TOKEN_METADATA_PROGRAM_ID.as_ref(),
+                mint.as_ref(),
+                METADATA_EDITION.as_bytes(),
+            ],
+            &TOKEN_METADATA_PROGRAM_ID,
+        )
+    }
+}
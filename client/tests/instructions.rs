@@ -0,0 +1,190 @@
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{AnchorSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use solana_sdk::signature::{Keypair, Signer};
+use voucher_nft::constants::TOKEN_METADATA_PROGRAM_ID;
+use voucher_nft::{accounts, instruction};
+use voucher_nft::{AddRepayVoucherParams, ApplyRepayDiscountParams, ClaimTicket, SetPauseParams};
+use voucher_nft_client::{instructions, Pda, PROGRAM_ID};
+
+// Anchor prefixes the instruction data with the first 8 bytes of sha256("global:<name>")
+fn sighash(name: &str) -> Vec<u8> {
+    hash(format!("global:{}", name).as_bytes()).to_bytes()[..8].to_vec()
+}
+
+fn signers(metas: &[AccountMeta]) -> Vec<Pubkey> {
+    metas
+        .iter()
+        .filter(|meta| meta.is_signer)
+        .map(|meta| meta.pubkey)
+        .collect()
+}
+
+#[test]
+fn set_pause_encodes_params_after_sighash() {
+    let pda = Pda::default();
+    let admin = Pubkey::new_unique();
+    let params = SetPauseParams {
+        paused: false,
+        mint_paused: true,
+        airdrop_paused: false,
+        redeem_paused: true,
+    };
+    let ix = instructions::set_pause(&pda, &admin, params.clone());
+
+    assert_eq!(ix.program_id, PROGRAM_ID);
+    assert_eq!(ix.data, [sighash("set_pause"), vec![0, 1, 0, 1]].concat());
+    assert_eq!(ix.data, instruction::SetPause { params }.data());
+    assert_eq!(
+        ix.accounts,
+        accounts::SetPause {
+            config: pda.config().0,
+            admin,
+        }
+        .to_account_metas(None)
+    );
+    assert_eq!(signers(&ix.accounts), vec![admin]);
+}
+
+#[test]
+fn add_voucher_repay_information_passes_every_voucher_kind() {
+    let pda = Pda::default();
+    let operator = Pubkey::new_unique();
+    let vault = pda.vault("VAULT").0;
+    let mint = Pubkey::new_unique();
+    let params = AddRepayVoucherParams {
+        discount_percentage: 1000,
+        maximum_amount: 30,
+        max_uses: 2,
+        start_time: 100,
+        end_time: 1000,
+    };
+    let ix =
+        instructions::add_voucher_repay_information(&pda, &operator, &vault, &mint, params.clone());
+
+    assert_eq!(
+        ix.data,
+        [
+            sighash("add_voucher_repay_information"),
+            params.try_to_vec().unwrap()
+        ]
+        .concat()
+    );
+    assert_eq!(
+        ix.data,
+        instruction::AddVoucherRepayInformation { params }.data()
+    );
+    assert_eq!(
+        ix.accounts,
+        accounts::AddRepayVoucher {
+            config: pda.config().0,
+            vault,
+            operator,
+            operator_role: pda.operator_role(&vault, &operator).0,
+            mint,
+            metadata_account: pda.metadata(&mint).0,
+            master_edition: pda.master_edition(&mint).0,
+            repay_voucher: pda.repay_voucher(&mint).0,
+            interest_rate_voucher: pda.interest_rate_voucher(&mint).0,
+            fixed_credit_voucher: pda.fixed_credit_voucher(&mint).0,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None)
+    );
+    assert_eq!(signers(&ix.accounts), vec![operator]);
+}
+
+#[test]
+fn apply_repay_discount_reads_the_holder_token_account() {
+    let pda = Pda::default();
+    let holder = Pubkey::new_unique();
+    let vault = pda.vault("VAULT").0;
+    let mint = Pubkey::new_unique();
+    let params = ApplyRepayDiscountParams {
+        amount: 500,
+        consume: false,
+    };
+    let ix = instructions::apply_repay_discount(&pda, &holder, &vault, &mint, params.clone());
+
+    assert_eq!(
+        ix.data,
+        [
+            sighash("apply_repay_discount"),
+            500u64.to_le_bytes().to_vec(),
+            vec![0]
+        ]
+        .concat()
+    );
+    assert_eq!(ix.data, instruction::ApplyRepayDiscount { params }.data());
+    assert!(ix.accounts.contains(&AccountMeta::new(
+        get_associated_token_address(&holder, &mint),
+        false
+    )));
+    assert!(ix
+        .accounts
+        .contains(&AccountMeta::new(pda.repay_voucher(&mint).0, false)));
+    assert_eq!(signers(&ix.accounts), vec![holder]);
+}
+
+#[test]
+fn get_interest_rate_reduction_takes_no_args() {
+    let pda = Pda::default();
+    let holder = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let ix = instructions::get_interest_rate_reduction(&pda, &holder, &mint);
+
+    assert_eq!(ix.data, sighash("get_interest_rate_reduction"));
+    assert_eq!(ix.data, instruction::GetInterestRateReduction {}.data());
+    assert_eq!(
+        ix.accounts,
+        accounts::GetInterestRateReduction {
+            config: pda.config().0,
+            holder,
+            mint,
+            holder_token_account: get_associated_token_address(&holder, &mint),
+            interest_rate_voucher: pda.interest_rate_voucher(&mint).0,
+        }
+        .to_account_metas(None)
+    );
+    assert!(signers(&ix.accounts).is_empty());
+}
+
+#[test]
+fn with_signer_marks_the_recipient() {
+    let pda = Pda::default();
+    let operator = Pubkey::new_unique();
+    let vault = pda.vault("VAULT").0;
+    let mint = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+    let ix = instructions::operator_airdrop(&pda, &operator, &vault, &mint, &user);
+    assert_eq!(signers(&ix.accounts), vec![operator]);
+
+    let ix = instructions::with_signer(ix, &user);
+    assert_eq!(signers(&ix.accounts), vec![operator, user]);
+}
+
+#[test]
+fn claim_ticket_signature_signs_the_tagged_message() {
+    let pda = Pda::default();
+    let ticket_signer = Keypair::new();
+    let ticket = ClaimTicket {
+        vault: pda.vault("VAULT").0,
+        mint: Pubkey::new_unique(),
+        recipient: Pubkey::new_unique(),
+        expiry: 1000,
+        nonce: 3,
+    };
+    let ix = instructions::claim_ticket_signature(&pda, &ticket_signer, &ticket);
+
+    assert_eq!(
+        ix.program_id,
+        anchor_lang::solana_program::ed25519_program::id()
+    );
+    assert!(ix.accounts.is_empty());
+    // One signature: count and padding, the offsets, then pubkey, signature and message
+    assert_eq!(&ix.data[16..48], ticket_signer.pubkey().as_ref());
+    assert_eq!(&ix.data[112..], ticket.message(&PROGRAM_ID).as_slice());
+}
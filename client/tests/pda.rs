@@ -0,0 +1,106 @@
+use anchor_lang::prelude::Pubkey;
+use voucher_nft::constants::TOKEN_METADATA_PROGRAM_ID;
+use voucher_nft::VoucherKind;
+use voucher_nft_client::{Pda, PROGRAM_ID};
+
+// Seeds are spelled out instead of read from the program so a renamed seed fails here
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &PROGRAM_ID).0
+}
+
+#[test]
+fn derives_program_accounts_from_their_seeds() {
+    let pda = Pda::default();
+    let vault = pda.vault("VAULT").0;
+    let operator = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let merkle_root = [7u8; 32];
+
+    assert_eq!(pda.config().0, find(&[b"CONFIG"]));
+    assert_eq!(pda.authorator().0, find(&[b"AUTHORATOR"]));
+    assert_eq!(vault, find(&[b"VAULT", b"VAULT"]));
+    assert_eq!(
+        pda.operator_role(&vault, &operator).0,
+        find(&[b"OPERATOR_ROLE", vault.as_ref(), operator.as_ref()])
+    );
+    assert_eq!(
+        pda.repay_voucher(&mint).0,
+        find(&[b"REPAY_VOUCHER", mint.as_ref()])
+    );
+    assert_eq!(
+        pda.interest_rate_voucher(&mint).0,
+        find(&[b"INTEREST_RATE_VOUCHER", mint.as_ref()])
+    );
+    assert_eq!(
+        pda.fixed_credit_voucher(&mint).0,
+        find(&[b"FIXED_CREDIT_VOUCHER", mint.as_ref()])
+    );
+
+    let distributor = pda.claim_distributor(&vault, &merkle_root).0;
+    assert_eq!(
+        distributor,
+        find(&[b"CLAIM_DISTRIBUTOR", vault.as_ref(), merkle_root.as_ref()])
+    );
+    assert_eq!(
+        pda.claim_status(&distributor, &mint).0,
+        find(&[b"CLAIM_STATUS", distributor.as_ref(), mint.as_ref()])
+    );
+    assert_eq!(
+        pda.ticket_nonce(&vault, 7).0,
+        find(&[b"TICKET_NONCE", vault.as_ref(), &7u64.to_le_bytes()])
+    );
+}
+
+#[test]
+fn voucher_information_follows_the_kind() {
+    let pda = Pda::default();
+    let mint = Pubkey::new_unique();
+
+    assert_eq!(
+        pda.voucher_information(VoucherKind::Repay, &mint),
+        pda.repay_voucher(&mint)
+    );
+    assert_eq!(
+        pda.voucher_information(VoucherKind::InterestRate, &mint),
+        pda.interest_rate_voucher(&mint)
+    );
+    assert_eq!(
+        pda.voucher_information(VoucherKind::FixedCredit, &mint),
+        pda.fixed_credit_voucher(&mint)
+    );
+}
+
+#[test]
+fn metadata_accounts_belong_to_the_metadata_program() {
+    let pda = Pda::new(Pubkey::new_unique());
+    let mint = Pubkey::new_unique();
+    let metadata_seeds: &[&[u8]] = &[
+        b"metadata",
+        TOKEN_METADATA_PROGRAM_ID.as_ref(),
+        mint.as_ref(),
+    ];
+
+    assert_eq!(
+        pda.metadata(&mint),
+        Pubkey::find_program_address(metadata_seeds, &TOKEN_METADATA_PROGRAM_ID)
+    );
+    assert_eq!(
+        pda.master_edition(&mint),
+        Pubkey::find_program_address(
+            &[metadata_seeds, &[b"edition"]].concat(),
+            &TOKEN_METADATA_PROGRAM_ID
+        )
+    );
+}
+
+#[test]
+fn program_accounts_follow_the_program_id() {
+    let other = Pda::new(Pubkey::new_unique());
+
+    assert_eq!(Pda::default().program_id, PROGRAM_ID);
+    assert_ne!(other.config(), Pda::default().config());
+    assert_eq!(
+        other.config().0,
+        Pubkey::find_program_address(&[b"CONFIG"], &other.program_id).0
+    );
+}
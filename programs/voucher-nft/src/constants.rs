@@ -6,3 +6,11 @@ pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
 
 pub const METADATA_PREFIX: &str = "metadata";
 pub const METADATA_EDITION: &str = "edition";
+
+// Claim tree hashes are prefixed so an inner node can never pass as a leaf
+pub const MERKLE_LEAF_PREFIX: u8 = 0x00;
+pub const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+// Signed ticket messages start with this tag and the program id, so a ticket
+// signature cannot be replayed against another program or message format
+pub const CLAIM_TICKET_TAG: &[u8] = b"voucher_nft:claim_ticket";
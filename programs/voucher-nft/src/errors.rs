@@ -26,4 +26,56 @@ pub enum VoucherNftError {
 
     #[msg("Invalid Nft Mint")] // 0x177a
     InvalidNftMint,
+    #[msg("Voucher Not Started")]
+    VoucherNotStarted,
+    #[msg("Voucher Expired")]
+    VoucherExpired,
+    #[msg("Invalid Voucher Holder")]
+    InvalidVoucherHolder,
+    #[msg("Only Pending Admin")]
+    OnlyPendingAdmin,
+
+    #[msg("Vault Not Empty")] // 0x177f
+    VaultNotEmpty,
+    #[msg("Math Overflow")]
+    MathOverflow,
+    #[msg("Program Paused")]
+    ProgramPaused,
+    #[msg("Invalid Permissions")]
+    InvalidPermissions,
+    #[msg("Invalid Merkle Proof")]
+    InvalidMerkleProof,
+
+    #[msg("Claim Window Closed")] // 0x1784
+    ClaimWindowClosed,
+    #[msg("Claim Window Not Closed")]
+    ClaimWindowNotClosed,
+    #[msg("Invalid Ticket Signature")]
+    InvalidTicketSignature,
+    #[msg("Ticket Expired")]
+    TicketExpired,
+    #[msg("Voucher Already Active")]
+    VoucherAlreadyActive,
+
+    #[msg("Invalid End Time")] // 0x1789
+    InvalidEndTime,
+    #[msg("Voucher Not Closable")]
+    VoucherNotClosable,
+    #[msg("Voucher Revoked")]
+    VoucherRevoked,
+    #[msg("Recipient Not Signed")]
+    RecipientNotSigned,
+    #[msg("Invalid Rate Reduction")]
+    InvalidRateReduction,
+
+    #[msg("Invalid Voucher Duration")] // 0x178e
+    InvalidVoucherDuration,
+    #[msg("Invalid Credit Amount")]
+    InvalidCreditAmount,
+    #[msg("Invalid Currency Mint")]
+    InvalidCurrencyMint,
+    #[msg("Vault Count Not Tracked")]
+    VaultCountNotTracked,
+    #[msg("Voucher Already Registered")]
+    VoucherAlreadyRegistered,
 }
@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminAccepted {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigInitialized {
+    pub admin: Pubkey,
+    pub collection: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub paused: bool,
+    pub mint_paused: bool,
+    pub airdrop_paused: bool,
+    pub redeem_paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultAdded {
+    pub vault: Pubkey,
+    pub seed: String,
+    pub operator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultOperatorUpdated {
+    pub vault: Pubkey,
+    pub previous_operator: Pubkey,
+    pub operator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultSoulboundUpdated {
+    pub vault: Pubkey,
+    pub soulbound: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultClosed {
+    pub vault: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OperatorRoleGranted {
+    pub vault: Pubkey,
+    pub operator: Pubkey,
+    pub granted: u8,
+    pub permissions: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OperatorRoleRevoked {
+    pub vault: Pubkey,
+    pub operator: Pubkey,
+    pub revoked: u8,
+    pub permissions: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherMinted {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RepayVoucherRegistered {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub discount_percentage: u16,
+    pub maximum_amount: u32,
+    pub max_uses: u16,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RepayVoucherUpdated {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub discount_percentage: u16,
+    pub maximum_amount: u32,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RepayVoucherClosed {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub burned: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InterestRateVoucherRegistered {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub rate_reduction_bps: u16,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FixedCreditVoucherRegistered {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub currency_mint: Pubkey,
+    pub credit_amount: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherAirdropped {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimDistributorCreated {
+    pub distributor: Pubkey,
+    pub vault: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub end_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherClaimed {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    // Default pubkey for ticket claims
+    pub distributor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistributorClawedBack {
+    pub distributor: Pubkey,
+    pub vault: Pubkey,
+    pub claimed_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherRevokeUpdated {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub revoked: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RepayVoucherRedeemed {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub discount: u64,
+    pub uses_remaining: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FixedCreditVoucherRedeemed {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub credit: u64,
+    pub remaining_credit: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,34 @@
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        address = config.pending_admin @ OnlyPendingAdmin,
+    )]
+    pub pending_admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> ProgramResult {
+    let config = &mut ctx.accounts.config;
+    let previous_admin = config.admin;
+
+    msg!("Accept {} as new admin", ctx.accounts.pending_admin.key());
+    config.accept_admin()?;
+
+    emit!(AdminAccepted {
+        previous_admin,
+        admin: config.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::errors::VoucherNftError::*;
+use crate::events::*;
 use crate::states::*;
 use anchor_lang::prelude::*;
 
@@ -32,5 +33,13 @@ pub fn handler(ctx: Context<AddVault>, seed: String, operator: Pubkey) -> Progra
     let vault = &mut ctx.accounts.vault;
     let (_, bump) =
         Pubkey::find_program_address(&[Vault::SEED.as_bytes(), seed.as_bytes()], ctx.program_id);
-    vault.initialize(operator, seed, bump)
+    vault.initialize(operator, seed.clone(), bump)?;
+
+    emit!(VaultAdded {
+        vault: vault.key(),
+        seed,
+        operator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
 }
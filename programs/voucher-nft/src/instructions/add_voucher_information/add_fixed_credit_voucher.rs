@@ -0,0 +1,132 @@
+use crate::constants::*;
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct AddFixedCreditVoucher<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
+    #[account()]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Check this account exist in code
+    #[account()]
+    pub metadata_account: AccountInfo<'info>,
+
+    /// CHECK: Check this account exist in code
+    #[account()]
+    pub master_edition: AccountInfo<'info>,
+
+    #[account()]
+    pub currency_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        seeds = [FixedCreditVoucher::SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+        space = FixedCreditVoucher::SPACE,
+        payer = operator,
+    )]
+    pub fixed_credit_voucher: Box<Account<'info, FixedCreditVoucher>>,
+
+    /// CHECK: Must stay empty, see `VoucherHeader::assert_registrable`
+    #[account(
+        seeds = [RepayVoucher::SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+    )]
+    pub repay_voucher: AccountInfo<'info>,
+
+    /// CHECK: Must stay empty, see `VoucherHeader::assert_registrable`
+    #[account(
+        seeds = [InterestRateVoucher::SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+    )]
+    pub interest_rate_voucher: AccountInfo<'info>,
+
+    /// CHECK: The RENEC token metadata program
+    #[account(
+        address = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Clone)]
+pub struct AddFixedCreditVoucherParams {
+    pub credit_amount: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+pub fn handler(
+    ctx: Context<AddFixedCreditVoucher>,
+    params: AddFixedCreditVoucherParams,
+) -> ProgramResult {
+    let mint = &ctx.accounts.mint;
+    let currency_mint = &ctx.accounts.currency_mint;
+    let vault = &ctx.accounts.vault;
+    let metadata = &ctx.accounts.metadata_account;
+    let master_edition = &ctx.accounts.master_edition;
+    let fixed_credit_voucher = &mut ctx.accounts.fixed_credit_voucher;
+
+    msg!("Perform add fixed credit voucher");
+    ctx.accounts.config.assert_mint_allowed()?;
+    OperatorRole::assert_permission(
+        vault,
+        &ctx.accounts.operator.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::INFORMATION_MANAGER,
+        ctx.program_id,
+    )?;
+
+    let authorator = VoucherHeader::assert_registrable(
+        ctx.program_id,
+        &vault.key(),
+        &mint.key(),
+        metadata,
+        master_edition,
+        &[
+            &ctx.accounts.repay_voucher,
+            &ctx.accounts.interest_rate_voucher,
+        ],
+    )?;
+
+    fixed_credit_voucher.initialize(
+        &params,
+        currency_mint.key(),
+        mint.key(),
+        authorator,
+        vault.key(),
+    )?;
+
+    emit!(FixedCreditVoucherRegistered {
+        vault: vault.key(),
+        mint: mint.key(),
+        currency_mint: currency_mint.key(),
+        credit_amount: params.credit_amount,
+        start_time: params.start_time,
+        end_time: params.end_time,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Initialize fixed credit voucher success");
+    Ok(())
+}
@@ -1,24 +1,30 @@
 use crate::constants::*;
-use crate::errors::VoucherNftError::*;
+use crate::events::*;
 use crate::states::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
-use mpl_token_metadata::state::Metadata;
 
 #[derive(Accounts)]
 pub struct AddRepayVoucher<'info> {
     #[account(
-        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        seeds = [Config::SEED.as_bytes()],
         bump,
     )]
-    pub vault: Box<Account<'info, Vault>>,
+    pub config: Box<Account<'info, Config>>,
 
     #[account(
-        mut,
-        address = vault.operator @ OnlyOperator,
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
     )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
     pub operator: Signer<'info>,
 
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
     #[account()]
     pub mint: Box<Account<'info, Mint>>,
 
@@ -39,6 +45,20 @@ pub struct AddRepayVoucher<'info> {
     )]
     pub repay_voucher: Box<Account<'info, RepayVoucher>>,
 
+    /// CHECK: Must stay empty, see `VoucherHeader::assert_registrable`
+    #[account(
+        seeds = [InterestRateVoucher::SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+    )]
+    pub interest_rate_voucher: AccountInfo<'info>,
+
+    /// CHECK: Must stay empty, see `VoucherHeader::assert_registrable`
+    #[account(
+        seeds = [FixedCreditVoucher::SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+    )]
+    pub fixed_credit_voucher: AccountInfo<'info>,
+
     /// CHECK: THe RENEC token metadata program
     #[account(
         address = TOKEN_METADATA_PROGRAM_ID,
@@ -47,10 +67,13 @@ pub struct AddRepayVoucher<'info> {
     pub system_program: Program<'info, System>,
 }
 
+// `max_uses` sits before `start_time`, so clients encoding the params without it must be
+// rebuilt against the new layout. Zero registers a single use
 #[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Clone)]
 pub struct AddRepayVoucherParams {
     pub discount_percentage: u16,
     pub maximum_amount: u32,
+    pub max_uses: u16,
     pub start_time: i64,
     pub end_time: i64,
 }
@@ -63,97 +86,39 @@ pub fn handler(ctx: Context<AddRepayVoucher>, params: AddRepayVoucherParams) ->
     let repay_voucher = &mut ctx.accounts.repay_voucher;
 
     msg!("Perform add repay voucher");
-    let (calculated_metadata, _) = Pubkey::find_program_address(
-        &[
-            METADATA_PREFIX.as_bytes(),
-            TOKEN_METADATA_PROGRAM_ID.as_ref(),
-            mint.key().as_ref(),
-        ],
-        &TOKEN_METADATA_PROGRAM_ID,
-    );
-
-    if metadata.key() != calculated_metadata {
-        return Err(InvalidAccountArgument.into());
-    }
-
-    if metadata.data_is_empty() {
-        return Err(AccountNotInitialized.into());
-    }
-    msg!("Check metadata success");
+    ctx.accounts.config.assert_mint_allowed()?;
+    OperatorRole::assert_permission(
+        vault,
+        &ctx.accounts.operator.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::INFORMATION_MANAGER,
+        ctx.program_id,
+    )?;
 
-    let (calculated_master_edition, _) = Pubkey::find_program_address(
+    let authorator = VoucherHeader::assert_registrable(
+        ctx.program_id,
+        &vault.key(),
+        &mint.key(),
+        metadata,
+        master_edition,
         &[
-            METADATA_PREFIX.as_bytes(),
-            TOKEN_METADATA_PROGRAM_ID.as_ref(),
-            mint.key().as_ref(),
-            METADATA_EDITION.as_bytes(),
+            &ctx.accounts.interest_rate_voucher,
+            &ctx.accounts.fixed_credit_voucher,
         ],
-        &TOKEN_METADATA_PROGRAM_ID,
-    );
-
-    if master_edition.key() != calculated_master_edition {
-        return Err(InvalidAccountArgument.into());
-    }
-
-    if master_edition.data_is_empty() {
-        return Err(AccountNotInitialized.into());
-    }
-    msg!("Check master edition success");
-
-    let (authorator, _) =
-        Pubkey::find_program_address(&[Authorator::SEED.as_bytes()], ctx.program_id);
-
-    let metadata_account_data = &mut Metadata::from_account_info(metadata)?;
-    let metadata_creators = metadata_account_data.data.creators.clone();
-    match metadata_creators {
-        None => {
-            msg!("Creators is empty");
-            return Err(AuthoratorNotSigned.into());
-        }
-        Some(creators) => {
-            let authorator_creator = creators.iter().find(|c| c.address == authorator);
-            match authorator_creator {
-                None => {
-                    msg!("Authorator not found");
-                    return Err(AuthoratorNotSigned.into());
-                }
-                Some(creator) => {
-                    if creator.verified == false {
-                        msg!("Authorator not verified");
-                        return Err(AuthoratorNotSigned.into());
-                    }
-                    msg!("Verify authorator success");
-                }
-            }
-
-            let vault_creator = creators.iter().find(|c| c.address == vault.key());
-            match vault_creator {
-                None => {
-                    msg!("Vault not found");
-                    return Err(VaultNotSigned.into());
-                }
-                Some(creator) => {
-                    if creator.verified == false {
-                        msg!("Vault not verified");
-                        return Err(VaultNotSigned.into());
-                    }
-                    msg!("Verify vault success");
-                }
-            }
-        }
-    }
-
-    msg!("Verify creators success");
-
-    repay_voucher.initialize(
-        params.discount_percentage,
-        params.maximum_amount,
-        params.start_time,
-        params.end_time,
-        mint.key(),
-        authorator,
     )?;
 
+    repay_voucher.initialize(&params, mint.key(), authorator, vault.key())?;
+
+    emit!(RepayVoucherRegistered {
+        vault: vault.key(),
+        mint: mint.key(),
+        discount_percentage: params.discount_percentage,
+        maximum_amount: params.maximum_amount,
+        max_uses: repay_voucher.max_uses,
+        start_time: params.start_time,
+        end_time: params.end_time,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     msg!("Initialize repay voucher success");
     Ok(())
 }
@@ -0,0 +1,126 @@
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CloseRepayVoucher<'info> {
+    #[account(
+        mut,
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Only used when burning the voucher, will check in the program
+    #[account(mut)]
+    pub vault_token_account: AccountInfo<'info>,
+
+    /// CHECK: See `RepayVoucher::assert_vault`
+    #[account()]
+    pub metadata_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [RepayVoucher::SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+        close = operator,
+    )]
+    pub repay_voucher: Box<Account<'info, RepayVoucher>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CloseRepayVoucher>, burn_vault_voucher: bool) -> ProgramResult {
+    let vault = &ctx.accounts.vault;
+    let mint = &ctx.accounts.mint;
+    let repay_voucher = &mut ctx.accounts.repay_voucher;
+    let vault_token_account = &ctx.accounts.vault_token_account;
+
+    msg!("Perform close repay voucher {}", mint.key());
+    OperatorRole::assert_permission(
+        vault,
+        &ctx.accounts.operator.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::INFORMATION_MANAGER,
+        ctx.program_id,
+    )?;
+    repay_voucher.assert_vault(ctx.program_id, &vault.key(), &ctx.accounts.metadata_account)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if !repay_voucher.is_redeemed() && current_time <= repay_voucher.end_time {
+        msg!("Repay voucher is neither redeemed nor expired");
+        return Err(VoucherNotClosable.into());
+    }
+
+    if !burn_vault_voucher {
+        emit!(RepayVoucherClosed {
+            vault: vault.key(),
+            mint: mint.key(),
+            burned: false,
+            timestamp: current_time,
+        });
+        msg!("Close repay voucher success");
+        return Ok(());
+    }
+
+    if vault_token_account.key() != get_associated_token_address(&vault.key(), &mint.key()) {
+        msg!("Invalid vault token account");
+        return Err(InvalidAccountArgument.into());
+    }
+
+    let token_account = Account::<TokenAccount>::try_from(vault_token_account)?;
+    if token_account.amount != 1 {
+        msg!("Voucher is not held by the vault");
+        return Err(InvalidAccountArgument.into());
+    }
+
+    let vault_seeds: &[&[u8]] = &[Vault::SEED.as_bytes(), vault.seed.as_bytes(), &[vault.bump]];
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Burn {
+                mint: mint.to_account_info(),
+                to: vault_token_account.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            &[vault_seeds],
+        ),
+        1,
+    )?;
+    msg!("Burn vault voucher success");
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: vault_token_account.to_account_info(),
+            destination: ctx.accounts.operator.to_account_info(),
+            authority: vault.to_account_info(),
+        },
+        &[vault_seeds],
+    ))?;
+
+    ctx.accounts.vault.withdraw_voucher()?;
+
+    emit!(RepayVoucherClosed {
+        vault: ctx.accounts.vault.key(),
+        mint: mint.key(),
+        burned: true,
+        timestamp: current_time,
+    });
+
+    msg!("Close repay voucher success");
+    Ok(())
+}
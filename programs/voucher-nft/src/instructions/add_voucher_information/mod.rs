@@ -1,3 +1,11 @@
+pub mod add_fixed_credit_voucher;
+pub mod add_interest_rate_voucher;
 pub mod add_repay_voucher;
+pub mod close_repay_voucher;
+pub mod update_repay_voucher;
 
+pub use add_fixed_credit_voucher::*;
+pub use add_interest_rate_voucher::*;
 pub use add_repay_voucher::*;
+pub use close_repay_voucher::*;
+pub use update_repay_voucher::*;
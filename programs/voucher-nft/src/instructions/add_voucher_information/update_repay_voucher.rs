@@ -0,0 +1,77 @@
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateRepayVoucher<'info> {
+    #[account(
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account()]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
+    /// CHECK: See `RepayVoucher::assert_vault`
+    #[account()]
+    pub metadata_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [RepayVoucher::SEED.as_bytes(), repay_voucher.nft_mint.as_ref()],
+        bump,
+    )]
+    pub repay_voucher: Box<Account<'info, RepayVoucher>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Clone)]
+pub struct UpdateRepayVoucherParams {
+    pub discount_percentage: u16,
+    pub maximum_amount: u32,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+pub fn handler(
+    ctx: Context<UpdateRepayVoucher>,
+    params: UpdateRepayVoucherParams,
+) -> ProgramResult {
+    let vault = &ctx.accounts.vault;
+    let repay_voucher = &mut ctx.accounts.repay_voucher;
+
+    msg!("Perform update repay voucher {}", repay_voucher.nft_mint);
+    OperatorRole::assert_permission(
+        vault,
+        &ctx.accounts.operator.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::INFORMATION_MANAGER,
+        ctx.program_id,
+    )?;
+    repay_voucher.assert_vault(ctx.program_id, &vault.key(), &ctx.accounts.metadata_account)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    repay_voucher.update(
+        params.discount_percentage,
+        params.maximum_amount,
+        params.start_time,
+        params.end_time,
+        current_time,
+    )?;
+
+    emit!(RepayVoucherUpdated {
+        vault: vault.key(),
+        mint: repay_voucher.nft_mint,
+        discount_percentage: repay_voucher.discount_percentage,
+        maximum_amount: repay_voucher.maximum_amount,
+        start_time: repay_voucher.start_time,
+        end_time: repay_voucher.end_time,
+        timestamp: current_time,
+    });
+    msg!("Update repay voucher success");
+    Ok(())
+}
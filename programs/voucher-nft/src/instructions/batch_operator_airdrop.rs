@@ -0,0 +1,153 @@
+use crate::constants::*;
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct BatchOperatorAirdrop<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
+    /// CHECK: The RENEC token metadata program
+    #[account(
+        address = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub token_metadata_program: AccountInfo<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Each airdrop is passed through remaining accounts as
+// [mint, master_edition, vault_token_account, user, user_token_account],
+// `user` must sign when the vault is soulbound
+pub const AIRDROP_ACCOUNTS_LEN: usize = 5;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, BatchOperatorAirdrop<'info>>,
+) -> ProgramResult {
+    let vault = &ctx.accounts.vault;
+    let remaining_accounts = ctx.remaining_accounts;
+
+    msg!(
+        "Batch airdrop {} accounts from vault {}",
+        remaining_accounts.len(),
+        vault.key()
+    );
+    ctx.accounts.config.assert_airdrop_allowed()?;
+    OperatorRole::assert_permission(
+        vault,
+        &ctx.accounts.operator.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::AIRDROPPER,
+        ctx.program_id,
+    )?;
+
+    if remaining_accounts.is_empty() || remaining_accounts.len() % AIRDROP_ACCOUNTS_LEN != 0 {
+        msg!("Invalid number of remaining accounts");
+        return Err(InvalidAccountArgument.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let mut airdrop_count: u64 = 0;
+    for airdrop_accounts in remaining_accounts.chunks(AIRDROP_ACCOUNTS_LEN) {
+        let mint_info = &airdrop_accounts[0];
+        let master_edition = &airdrop_accounts[1];
+        let vault_token_account = &airdrop_accounts[2];
+        let user = &airdrop_accounts[3];
+        let user_token_account = &airdrop_accounts[4];
+
+        msg!("Airdrop token {} to user {}", mint_info.key(), user.key());
+
+        let mint = Account::<Mint>::try_from(mint_info)?;
+        assert_valid_voucher_nft(&mint, &mint_info.key(), master_edition)?;
+
+        if vault_token_account.key() != get_associated_token_address(&vault.key(), &mint_info.key())
+        {
+            msg!("Invalid vault token account");
+            return Err(InvalidAccountArgument.into());
+        }
+        // Deserialize to ensure the vault token account is owned by the token program
+        Account::<TokenAccount>::try_from(vault_token_account)?;
+
+        if user_token_account.key() != get_associated_token_address(&user.key(), &mint_info.key()) {
+            msg!("Invalid user token account");
+            return Err(InvalidAccountArgument.into());
+        }
+
+        if user_token_account.data_is_empty() {
+            msg!("Creating user token account");
+            associated_token::create(CpiContext::new(
+                ctx.accounts.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: ctx.accounts.operator.to_account_info(),
+                    associated_token: user_token_account.clone(),
+                    authority: user.clone(),
+                    mint: mint_info.clone(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+            ))?;
+        }
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: vault_token_account.clone(),
+                    to: user_token_account.clone(),
+                    authority: vault.to_account_info(),
+                },
+                &[&[Vault::SEED.as_bytes(), vault.seed.as_bytes(), &[vault.bump]]],
+            ),
+            1,
+        )?;
+        if vault.soulbound {
+            lock_soulbound_voucher(
+                vault,
+                user.clone(),
+                user_token_account.clone(),
+                master_edition.clone(),
+                mint_info.clone(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.token_metadata_program.to_account_info(),
+            )?;
+        }
+
+        emit!(VoucherAirdropped {
+            vault: vault.key(),
+            mint: mint_info.key(),
+            recipient: user.key(),
+            timestamp: current_time,
+        });
+        airdrop_count += 1;
+    }
+
+    ctx.accounts.vault.withdraw_vouchers(airdrop_count)?;
+
+    msg!("Batch airdrop {} nfts success", airdrop_count);
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use crate::constants::*;
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimVoucher<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [
+            ClaimDistributor::SEED.as_bytes(),
+            vault.key().as_ref(),
+            distributor.merkle_root.as_ref(),
+        ],
+        bump,
+    )]
+    pub distributor: Box<Account<'info, ClaimDistributor>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account()]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Will check in the program
+    #[account()]
+    pub master_edition: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        seeds = [ClaimStatus::SEED.as_bytes(), distributor.key().as_ref(), mint.key().as_ref()],
+        bump,
+        space = ClaimStatus::SPACE,
+        payer = user,
+    )]
+    pub claim_status: Box<Account<'info, ClaimStatus>>,
+
+    /// CHECK: The RENEC token metadata program
+    #[account(
+        address = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub token_metadata_program: AccountInfo<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<ClaimVoucher>, proof: Vec<[u8; 32]>) -> ProgramResult {
+    let mint = &ctx.accounts.mint;
+    let vault = &ctx.accounts.vault;
+    let user = &ctx.accounts.user;
+    let distributor = &ctx.accounts.distributor;
+
+    msg!(
+        "Claim token {} to user {} from distributor {}",
+        mint.key(),
+        user.key(),
+        distributor.key()
+    );
+    ctx.accounts.config.assert_airdrop_allowed()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if distributor.is_closed(current_time) {
+        return Err(ClaimWindowClosed.into());
+    }
+
+    let leaf = claim_leaf(&user.key(), &mint.key());
+    if !verify_merkle_proof(&proof, distributor.merkle_root, leaf) {
+        return Err(InvalidMerkleProof.into());
+    }
+    msg!("Verify merkle proof success");
+
+    assert_valid_voucher_nft(mint, &mint.key(), &ctx.accounts.master_edition)?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            &[&[Vault::SEED.as_bytes(), vault.seed.as_bytes(), &[vault.bump]]],
+        ),
+        1,
+    )?;
+
+    if vault.soulbound {
+        lock_soulbound_voucher(
+            vault,
+            user.to_account_info(),
+            ctx.accounts.user_token_account.to_account_info(),
+            ctx.accounts.master_edition.to_account_info(),
+            mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        )?;
+    }
+
+    ctx.accounts.claim_status.initialize(
+        ctx.accounts.distributor.key(),
+        ctx.accounts.user.key(),
+        ctx.accounts.mint.key(),
+        current_time,
+    )?;
+    ctx.accounts.distributor.claimed_count = ctx
+        .accounts
+        .distributor
+        .claimed_count
+        .checked_add(1)
+        .ok_or(MathOverflow)?;
+    ctx.accounts.vault.withdraw_voucher()?;
+
+    emit!(VoucherClaimed {
+        vault: ctx.accounts.vault.key(),
+        mint: ctx.accounts.mint.key(),
+        recipient: ctx.accounts.user.key(),
+        distributor: ctx.accounts.distributor.key(),
+        timestamp: current_time,
+    });
+    msg!("Claim nft success");
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use crate::constants::*;
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use solana_program::sysvar;
+
+#[derive(Accounts)]
+#[instruction(ticket: ClaimTicket)]
+pub struct ClaimWithTicket<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+        constraint = vault.key() == ticket.vault @ InvalidAccountArgument,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: The operator who signed the ticket, verified through the ed25519 instruction
+    #[account()]
+    pub ticket_signer: AccountInfo<'info>,
+
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = ticket.recipient @ InvalidAccountArgument,
+    )]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        address = ticket.mint @ InvalidAccountArgument,
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Will check in the program
+    #[account()]
+    pub master_edition: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        seeds = [
+            TicketNonce::SEED.as_bytes(),
+            vault.key().as_ref(),
+            ticket.nonce.to_le_bytes().as_ref(),
+        ],
+        bump,
+        space = TicketNonce::SPACE,
+        payer = recipient,
+    )]
+    pub ticket_nonce: Box<Account<'info, TicketNonce>>,
+
+    /// CHECK: The instructions sysvar
+    #[account(
+        address = sysvar::instructions::ID,
+    )]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// CHECK: The RENEC token metadata program
+    #[account(
+        address = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub token_metadata_program: AccountInfo<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Clone)]
+pub struct ClaimTicket {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub expiry: i64,
+    pub nonce: u64,
+}
+
+impl ClaimTicket {
+    // The bytes the ticket signer signs: tag, program id, then the ticket fields in
+    // their Borsh layout
+    pub fn message(&self, program_id: &Pubkey) -> Vec<u8> {
+        [
+            CLAIM_TICKET_TAG,
+            program_id.as_ref(),
+            self.vault.as_ref(),
+            self.mint.as_ref(),
+            self.recipient.as_ref(),
+            &self.expiry.to_le_bytes(),
+            &self.nonce.to_le_bytes(),
+        ]
+        .concat()
+    }
+}
+
+pub fn handler(ctx: Context<ClaimWithTicket>, ticket: ClaimTicket) -> ProgramResult {
+    let mint = &ctx.accounts.mint;
+    let vault = &ctx.accounts.vault;
+    let ticket_signer = &ctx.accounts.ticket_signer;
+
+    msg!(
+        "Claim token {} to recipient {} with ticket nonce {}",
+        mint.key(),
+        ticket.recipient,
+        ticket.nonce
+    );
+    ctx.accounts.config.assert_airdrop_allowed()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time > ticket.expiry {
+        return Err(TicketExpired.into());
+    }
+
+    OperatorRole::assert_permission(
+        vault,
+        &ticket_signer.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::AIRDROPPER,
+        ctx.program_id,
+    )?;
+    assert_ed25519_signature(
+        &ctx.accounts.instructions_sysvar,
+        &ticket_signer.key(),
+        &ticket.message(ctx.program_id),
+    )?;
+    msg!("Verify ticket signature success");
+
+    assert_valid_voucher_nft(mint, &mint.key(), &ctx.accounts.master_edition)?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            &[&[Vault::SEED.as_bytes(), vault.seed.as_bytes(), &[vault.bump]]],
+        ),
+        1,
+    )?;
+
+    if vault.soulbound {
+        lock_soulbound_voucher(
+            vault,
+            ctx.accounts.recipient.to_account_info(),
+            ctx.accounts.recipient_token_account.to_account_info(),
+            ctx.accounts.master_edition.to_account_info(),
+            mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        )?;
+    }
+
+    ctx.accounts.ticket_nonce.initialize(
+        ticket.vault,
+        ticket.recipient,
+        ticket.nonce,
+        current_time,
+    )?;
+    ctx.accounts.vault.withdraw_voucher()?;
+
+    emit!(VoucherClaimed {
+        vault: ctx.accounts.vault.key(),
+        mint: ctx.accounts.mint.key(),
+        recipient: ticket.recipient,
+        distributor: Pubkey::default(),
+        timestamp: current_time,
+    });
+    msg!("Claim nft with ticket success");
+    Ok(())
+}
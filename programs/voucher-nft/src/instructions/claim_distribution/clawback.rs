@@ -0,0 +1,74 @@
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct Clawback<'info> {
+    #[account(
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            ClaimDistributor::SEED.as_bytes(),
+            vault.key().as_ref(),
+            distributor.merkle_root.as_ref(),
+        ],
+        bump,
+        close = payer,
+    )]
+    pub distributor: Box<Account<'info, ClaimDistributor>>,
+
+    /// CHECK: Receives the distributor rent
+    #[account(
+        mut,
+        address = distributor.payer @ InvalidAccountArgument,
+    )]
+    pub payer: AccountInfo<'info>,
+}
+
+// Unclaimed vouchers never leave the vault token accounts and stay counted in
+// `voucher_count`, so closing the distributor is enough to take them back and the
+// operator can hand them out again. Its rent returns to whoever paid it
+pub fn handler(ctx: Context<Clawback>) -> ProgramResult {
+    let vault = &ctx.accounts.vault;
+    let distributor = &ctx.accounts.distributor;
+
+    msg!(
+        "Clawback distributor {} of vault {}, claimed {}",
+        distributor.key(),
+        vault.key(),
+        distributor.claimed_count
+    );
+    OperatorRole::assert_permission(
+        vault,
+        &ctx.accounts.operator.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::AIRDROPPER,
+        ctx.program_id,
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if !distributor.is_closed(current_time) {
+        return Err(ClaimWindowNotClosed.into());
+    }
+
+    emit!(DistributorClawedBack {
+        distributor: distributor.key(),
+        vault: vault.key(),
+        claimed_count: distributor.claimed_count,
+        timestamp: current_time,
+    });
+    Ok(())
+}
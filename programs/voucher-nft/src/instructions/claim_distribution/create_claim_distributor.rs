@@ -0,0 +1,91 @@
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(merkle_root: [u8; 32])]
+pub struct CreateClaimDistributor<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
+    #[account(
+        init,
+        seeds = [ClaimDistributor::SEED.as_bytes(), vault.key().as_ref(), merkle_root.as_ref()],
+        bump,
+        space = ClaimDistributor::SPACE,
+        payer = operator,
+    )]
+    pub distributor: Box<Account<'info, ClaimDistributor>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateClaimDistributor>,
+    merkle_root: [u8; 32],
+    end_time: i64,
+) -> ProgramResult {
+    let vault = &ctx.accounts.vault;
+    let distributor = &mut ctx.accounts.distributor;
+
+    msg!(
+        "Create claim distributor {} for vault {}",
+        distributor.key(),
+        vault.key()
+    );
+    ctx.accounts.config.assert_airdrop_allowed()?;
+    OperatorRole::assert_permission(
+        vault,
+        &ctx.accounts.operator.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::AIRDROPPER,
+        ctx.program_id,
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if end_time <= current_time {
+        return Err(ClaimWindowClosed.into());
+    }
+
+    let (_, bump) = Pubkey::find_program_address(
+        &[
+            ClaimDistributor::SEED.as_bytes(),
+            vault.key().as_ref(),
+            merkle_root.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    distributor.initialize(
+        vault.key(),
+        merkle_root,
+        end_time,
+        bump,
+        ctx.accounts.operator.key(),
+    )?;
+
+    emit!(ClaimDistributorCreated {
+        distributor: distributor.key(),
+        vault: vault.key(),
+        merkle_root,
+        end_time,
+        timestamp: current_time,
+    });
+    Ok(())
+}
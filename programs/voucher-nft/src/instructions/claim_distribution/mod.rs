@@ -0,0 +1,9 @@
+pub mod claim_voucher;
+pub mod claim_with_ticket;
+pub mod clawback;
+pub mod create_claim_distributor;
+
+pub use claim_voucher::*;
+pub use claim_with_ticket::*;
+pub use clawback::*;
+pub use create_claim_distributor::*;
@@ -0,0 +1,39 @@
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+        constraint = vault.voucher_count_tracked @ VaultCountNotTracked,
+        constraint = vault.voucher_count == 0 @ VaultNotEmpty,
+        close = admin,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        address = config.admin @ OnlyAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseVault>) -> ProgramResult {
+    msg!("Close vault {}", ctx.accounts.vault.key());
+
+    emit!(VaultClosed {
+        vault: ctx.accounts.vault.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey)]
+pub struct GrantOperatorRole<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [OperatorRole::SEED.as_bytes(), vault.key().as_ref(), operator.as_ref()],
+        bump,
+        payer = admin,
+        space = OperatorRole::SPACE,
+    )]
+    pub operator_role: Box<Account<'info, OperatorRole>>,
+
+    #[account(
+        mut,
+        address = config.admin @ OnlyAdmin,
+    )]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<GrantOperatorRole>,
+    operator: Pubkey,
+    permissions: u8,
+) -> ProgramResult {
+    let vault = &ctx.accounts.vault;
+    let operator_role = &mut ctx.accounts.operator_role;
+
+    msg!(
+        "Grant permissions {} of vault {} to operator {}",
+        permissions,
+        vault.key(),
+        operator
+    );
+    let (_, bump) = Pubkey::find_program_address(
+        &[
+            OperatorRole::SEED.as_bytes(),
+            vault.key().as_ref(),
+            operator.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    operator_role.initialize(vault.key(), operator, bump)?;
+    operator_role.grant(permissions)?;
+
+    emit!(OperatorRoleGranted {
+        vault: vault.key(),
+        operator,
+        granted: permissions,
+        permissions: operator_role.permissions,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
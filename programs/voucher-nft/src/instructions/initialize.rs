@@ -1,4 +1,5 @@
 use crate::constants::*;
+use crate::events::*;
 use crate::states::*;
 use crate::MetadataParams;
 use anchor_lang::prelude::*;
@@ -165,5 +166,10 @@ pub fn handler(ctx: Context<Initialize>, params: MetadataParams) -> ProgramResul
         &[&[Authorator::SEED.as_bytes(), &[authorator.bump]]],
     )?;
 
+    emit!(ConfigInitialized {
+        admin: admin.key(),
+        collection: relend_collection.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
@@ -0,0 +1,167 @@
+use crate::constants::*;
+use crate::events::*;
+use crate::instructions::{mint_voucher_nft, AddRepayVoucherParams, VoucherNftAccounts};
+use crate::states::*;
+use crate::MetadataParams;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct MintRepayVoucher<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [Authorator::SEED.as_bytes()],
+        bump,
+    )]
+    pub authorator: Box<Account<'info, Authorator>>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = operator,
+        mint::decimals = 0,
+        mint::authority = vault.key(),
+        mint::freeze_authority = vault.key(),
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = operator,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Token_metadata_program will check this
+    #[account(mut)]
+    pub metadata_account: AccountInfo<'info>,
+
+    /// CHECK: Token_metadata_program will check this
+    #[account(mut)]
+    pub master_edition: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = config.collection,
+    )]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Token_metadata_program will check this
+    #[account(mut)]
+    pub collection_metadata_account: AccountInfo<'info>,
+
+    /// CHECK: Token_metadata_program will check this
+    #[account(mut)]
+    pub collection_master_edition: AccountInfo<'info>,
+
+    #[account(
+        init,
+        seeds = [RepayVoucher::SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+        space = RepayVoucher::SPACE,
+        payer = operator,
+    )]
+    pub repay_voucher: Box<Account<'info, RepayVoucher>>,
+
+    /// CHECK: The RENEC token metadata program
+    #[account(
+        address = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<MintRepayVoucher>,
+    metadata_params: MetadataParams,
+    repay_voucher_params: AddRepayVoucherParams,
+) -> ProgramResult {
+    let vault = &ctx.accounts.vault;
+    let mint = &ctx.accounts.mint;
+    let authorator = &ctx.accounts.authorator;
+    let operator = &ctx.accounts.operator;
+    msg!(
+        "Minting repay voucher NFT {} with vault {}",
+        mint.key(),
+        vault.key()
+    );
+    ctx.accounts.config.assert_mint_allowed()?;
+    OperatorRole::assert_permission(
+        vault,
+        &operator.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::MINTER | OperatorRole::INFORMATION_MANAGER,
+        ctx.program_id,
+    )?;
+
+    mint_voucher_nft(
+        VoucherNftAccounts {
+            vault,
+            authorator,
+            operator: operator.to_account_info(),
+            mint: mint.to_account_info(),
+            token_account: ctx.accounts.vault_token_account.to_account_info(),
+            metadata_account: ctx.accounts.metadata_account.to_account_info(),
+            master_edition: ctx.accounts.master_edition.to_account_info(),
+            collection_mint: ctx.accounts.collection_mint.to_account_info(),
+            collection_metadata_account: ctx.accounts.collection_metadata_account.to_account_info(),
+            collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        },
+        &metadata_params,
+    )?;
+
+    ctx.accounts.repay_voucher.initialize(
+        &repay_voucher_params,
+        ctx.accounts.mint.key(),
+        ctx.accounts.authorator.key(),
+        ctx.accounts.vault.key(),
+    )?;
+    msg!("Initialize repay voucher success");
+
+    emit!(VoucherMinted {
+        vault: ctx.accounts.vault.key(),
+        mint: ctx.accounts.mint.key(),
+        owner: ctx.accounts.vault.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    emit!(RepayVoucherRegistered {
+        vault: ctx.accounts.vault.key(),
+        mint: ctx.accounts.mint.key(),
+        discount_percentage: repay_voucher_params.discount_percentage,
+        maximum_amount: repay_voucher_params.maximum_amount,
+        max_uses: ctx.accounts.repay_voucher.max_uses,
+        start_time: repay_voucher_params.start_time,
+        end_time: repay_voucher_params.end_time,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    ctx.accounts.vault.deposit_voucher()?;
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use crate::constants::*;
-use crate::errors::*;
+use crate::events::*;
 use crate::states::*;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
@@ -19,6 +19,7 @@ pub struct MintVoucher<'info> {
     pub config: Box<Account<'info, Config>>,
 
     #[account(
+        mut,
         seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
         bump,
     )]
@@ -31,12 +32,13 @@ pub struct MintVoucher<'info> {
     )]
     pub authorator: Box<Account<'info, Authorator>>,
 
-    #[account(
-        mut,
-        address = vault.operator @ VoucherNftError::OnlyOperator,
-    )]
+    #[account(mut)]
     pub operator: Signer<'info>,
 
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
     #[account(
         init,
         payer = operator,
@@ -97,25 +99,92 @@ pub struct MetadataParams {
 pub fn handler(ctx: Context<MintVoucher>, params: MetadataParams) -> ProgramResult {
     let vault = &ctx.accounts.vault;
     let mint = &ctx.accounts.mint;
-    let authorator = &ctx.accounts.authorator;
-    let token_metadata_program = &ctx.accounts.token_metadata_program;
-    let metadata_account = &mut ctx.accounts.metadata_account;
-    let operator = &mut ctx.accounts.operator;
+    let operator = &ctx.accounts.operator;
     msg!(
         "Minting voucher NFT {} with vault {}",
         mint.key(),
         vault.key()
     );
+    ctx.accounts.config.assert_mint_allowed()?;
+    OperatorRole::assert_permission(
+        vault,
+        &operator.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::MINTER,
+        ctx.program_id,
+    )?;
+
+    mint_voucher_nft(
+        VoucherNftAccounts {
+            vault,
+            authorator: &ctx.accounts.authorator,
+            operator: operator.to_account_info(),
+            mint: mint.to_account_info(),
+            token_account: ctx.accounts.vault_token_account.to_account_info(),
+            metadata_account: ctx.accounts.metadata_account.to_account_info(),
+            master_edition: ctx.accounts.master_edition.to_account_info(),
+            collection_mint: ctx.accounts.collection_mint.to_account_info(),
+            collection_metadata_account: ctx.accounts.collection_metadata_account.to_account_info(),
+            collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        },
+        &params,
+    )?;
+
+    emit!(VoucherMinted {
+        vault: vault.key(),
+        mint: mint.key(),
+        owner: vault.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    ctx.accounts.vault.deposit_voucher()?;
+    Ok(())
+}
 
-    msg!("Minting NFT to vault");
+pub(crate) struct VoucherNftAccounts<'a, 'info> {
+    pub vault: &'a Account<'info, Vault>,
+    pub authorator: &'a Account<'info, Authorator>,
+    pub operator: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    // Token account receiving the freshly minted voucher
+    pub token_account: AccountInfo<'info>,
+    pub metadata_account: AccountInfo<'info>,
+    pub master_edition: AccountInfo<'info>,
+    pub collection_mint: AccountInfo<'info>,
+    pub collection_metadata_account: AccountInfo<'info>,
+    pub collection_master_edition: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+pub(crate) fn mint_voucher_nft(
+    accounts: VoucherNftAccounts,
+    params: &MetadataParams,
+) -> ProgramResult {
+    let vault = accounts.vault;
+    let authorator = accounts.authorator;
+    let mint = &accounts.mint;
+    let operator = &accounts.operator;
+    let metadata_account = &accounts.metadata_account;
+    let token_metadata_program = &accounts.token_metadata_program;
+
+    msg!(
+        "Minting NFT to token account {}",
+        accounts.token_account.key()
+    );
     let cpi_accounts = MintTo {
-        mint: mint.to_account_info(),
-        to: ctx.accounts.vault_token_account.to_account_info(),
+        mint: mint.clone(),
+        to: accounts.token_account.clone(),
         authority: vault.to_account_info(),
     };
     token::mint_to(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.clone(),
             cpi_accounts,
             &[&[Vault::SEED.as_bytes(), vault.seed.as_bytes(), &[vault.bump]]],
         ),
@@ -124,13 +193,13 @@ pub fn handler(ctx: Context<MintVoucher>, params: MetadataParams) -> ProgramResu
 
     msg!("Creating Metadata account");
     let metadata_account_infos = vec![
-        metadata_account.to_account_info(),
-        mint.to_account_info(),
+        metadata_account.clone(),
+        mint.clone(),
         vault.to_account_info(),
-        operator.to_account_info(),
+        operator.clone(),
         vault.to_account_info(),
-        ctx.accounts.system_program.to_account_info(),
-        ctx.accounts.rent.to_account_info(),
+        accounts.system_program.clone(),
+        accounts.rent.clone(),
     ];
     let creators = vec![
         mpl_token_metadata::state::Creator {
@@ -146,7 +215,7 @@ pub fn handler(ctx: Context<MintVoucher>, params: MetadataParams) -> ProgramResu
     ];
     let collection = Collection {
         verified: false,
-        key: ctx.accounts.collection_mint.key(),
+        key: accounts.collection_mint.key(),
     };
     invoke_signed(
         &create_metadata_accounts_v2(
@@ -171,10 +240,7 @@ pub fn handler(ctx: Context<MintVoucher>, params: MetadataParams) -> ProgramResu
     )?;
 
     msg!("Signing creator with authorator");
-    let sign_metadata_infos = vec![
-        metadata_account.to_account_info(),
-        authorator.to_account_info(),
-    ];
+    let sign_metadata_infos = vec![metadata_account.clone(), authorator.to_account_info()];
     invoke_signed(
         &sign_metadata(
             token_metadata_program.key(),
@@ -187,21 +253,21 @@ pub fn handler(ctx: Context<MintVoucher>, params: MetadataParams) -> ProgramResu
 
     msg!("Creating master edition");
     let master_edition_infos = vec![
-        ctx.accounts.master_edition.to_account_info(),
-        mint.to_account_info(),
+        accounts.master_edition.clone(),
+        mint.clone(),
         vault.to_account_info(),
-        operator.to_account_info(),
-        metadata_account.to_account_info(),
-        token_metadata_program.to_account_info(),
-        ctx.accounts.token_program.to_account_info(),
-        ctx.accounts.system_program.to_account_info(),
-        ctx.accounts.rent.to_account_info(),
+        operator.clone(),
+        metadata_account.clone(),
+        token_metadata_program.clone(),
+        accounts.token_program.clone(),
+        accounts.system_program.clone(),
+        accounts.rent.clone(),
     ];
 
     invoke_signed(
         &create_master_edition_v3(
             token_metadata_program.key(),
-            ctx.accounts.master_edition.key(),
+            accounts.master_edition.key(),
             mint.key(),
             vault.key(),
             vault.key(),
@@ -216,13 +282,13 @@ pub fn handler(ctx: Context<MintVoucher>, params: MetadataParams) -> ProgramResu
 
     msg!("Verifying nft inside the collection");
     let verify_collection_infos = vec![
-        token_metadata_program.to_account_info(),
-        metadata_account.to_account_info(),
+        token_metadata_program.clone(),
+        metadata_account.clone(),
         authorator.to_account_info(),
-        operator.to_account_info(),
-        ctx.accounts.collection_mint.to_account_info(),
-        ctx.accounts.collection_metadata_account.to_account_info(),
-        ctx.accounts.collection_master_edition.to_account_info(),
+        operator.clone(),
+        accounts.collection_mint.clone(),
+        accounts.collection_metadata_account.clone(),
+        accounts.collection_master_edition.clone(),
     ];
     invoke_signed(
         &verify_collection(
@@ -230,9 +296,9 @@ pub fn handler(ctx: Context<MintVoucher>, params: MetadataParams) -> ProgramResu
             metadata_account.key(),
             authorator.key(),
             operator.key(),
-            ctx.accounts.collection_mint.key(),
-            ctx.accounts.collection_metadata_account.key(),
-            ctx.accounts.collection_master_edition.key(),
+            accounts.collection_mint.key(),
+            accounts.collection_metadata_account.key(),
+            accounts.collection_master_edition.key(),
             None,
         ),
         verify_collection_infos.as_slice(),
@@ -0,0 +1,152 @@
+use crate::constants::*;
+use crate::events::*;
+use crate::instructions::{mint_voucher_nft, VoucherNftAccounts};
+use crate::states::*;
+use crate::utils::*;
+use crate::MetadataParams;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct MintVoucherTo<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [Authorator::SEED.as_bytes()],
+        bump,
+    )]
+    pub authorator: Box<Account<'info, Authorator>>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = operator,
+        mint::decimals = 0,
+        mint::authority = vault.key(),
+        mint::freeze_authority = vault.key(),
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: The recipient account address, must sign when the vault is soulbound
+    #[account()]
+    pub recipient: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Token_metadata_program will check this
+    #[account(mut)]
+    pub metadata_account: AccountInfo<'info>,
+
+    /// CHECK: Token_metadata_program will check this
+    #[account(mut)]
+    pub master_edition: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = config.collection,
+    )]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Token_metadata_program will check this
+    #[account(mut)]
+    pub collection_metadata_account: AccountInfo<'info>,
+
+    /// CHECK: Token_metadata_program will check this
+    #[account(mut)]
+    pub collection_master_edition: AccountInfo<'info>,
+
+    /// CHECK: The RENEC token metadata program
+    #[account(
+        address = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<MintVoucherTo>, params: MetadataParams) -> ProgramResult {
+    let vault = &ctx.accounts.vault;
+    let mint = &ctx.accounts.mint;
+    let operator = &ctx.accounts.operator;
+    msg!(
+        "Minting voucher NFT {} with vault {} to recipient {}",
+        mint.key(),
+        vault.key(),
+        ctx.accounts.recipient.key()
+    );
+    ctx.accounts.config.assert_mint_allowed()?;
+    ctx.accounts.config.assert_airdrop_allowed()?;
+    OperatorRole::assert_permission(
+        vault,
+        &operator.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::MINTER,
+        ctx.program_id,
+    )?;
+
+    mint_voucher_nft(
+        VoucherNftAccounts {
+            vault,
+            authorator: &ctx.accounts.authorator,
+            operator: operator.to_account_info(),
+            mint: mint.to_account_info(),
+            token_account: ctx.accounts.recipient_token_account.to_account_info(),
+            metadata_account: ctx.accounts.metadata_account.to_account_info(),
+            master_edition: ctx.accounts.master_edition.to_account_info(),
+            collection_mint: ctx.accounts.collection_mint.to_account_info(),
+            collection_metadata_account: ctx.accounts.collection_metadata_account.to_account_info(),
+            collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        },
+        &params,
+    )?;
+
+    if vault.soulbound {
+        lock_soulbound_voucher(
+            vault,
+            ctx.accounts.recipient.to_account_info(),
+            ctx.accounts.recipient_token_account.to_account_info(),
+            ctx.accounts.master_edition.to_account_info(),
+            mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        )?;
+    }
+
+    emit!(VoucherMinted {
+        vault: vault.key(),
+        mint: mint.key(),
+        owner: ctx.accounts.recipient.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
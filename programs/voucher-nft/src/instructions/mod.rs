@@ -1,11 +1,39 @@
+pub mod accept_admin;
 pub mod add_vault;
 pub mod add_voucher_information;
+pub mod batch_operator_airdrop;
+pub mod claim_distribution;
+pub mod close_vault;
+pub mod grant_operator_role;
 pub mod initialize;
+pub mod mint_repay_voucher;
 pub mod mint_voucher;
+pub mod mint_voucher_to;
 pub mod operator_airdrop;
+pub mod propose_admin;
+pub mod redeem_voucher;
+pub mod revoke_operator_role;
+pub mod revoke_voucher;
+pub mod set_pause;
+pub mod set_vault_operator;
+pub mod set_vault_soulbound;
 
+pub use accept_admin::*;
 pub use add_vault::*;
 pub use add_voucher_information::*;
+pub use batch_operator_airdrop::*;
+pub use claim_distribution::*;
+pub use close_vault::*;
+pub use grant_operator_role::*;
 pub use initialize::*;
+pub use mint_repay_voucher::*;
 pub use mint_voucher::*;
+pub use mint_voucher_to::*;
 pub use operator_airdrop::*;
+pub use propose_admin::*;
+pub use redeem_voucher::*;
+pub use revoke_operator_role::*;
+pub use revoke_voucher::*;
+pub use set_pause::*;
+pub use set_vault_operator::*;
+pub use set_vault_soulbound::*;
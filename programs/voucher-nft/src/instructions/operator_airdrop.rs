@@ -1,6 +1,7 @@
-use crate::constants::{METADATA_EDITION, METADATA_PREFIX, TOKEN_METADATA_PROGRAM_ID};
-use crate::errors::VoucherNftError::*;
+use crate::constants::*;
+use crate::events::*;
 use crate::states::*;
+use crate::utils::*;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
@@ -8,18 +9,26 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount};
 #[derive(Accounts)]
 pub struct OperatorAirdrop<'info> {
     #[account(
-        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        seeds = [Config::SEED.as_bytes()],
         bump,
     )]
-    pub vault: Box<Account<'info, Vault>>,
+    pub config: Box<Account<'info, Config>>,
 
     #[account(
         mut,
-        address = vault.operator @ OnlyOperator,
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
     )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
     pub operator: Signer<'info>,
 
-    /// CHECK: The user account address
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
+    /// CHECK: The user account address, must sign when the vault is soulbound
     #[account()]
     pub user: AccountInfo<'info>,
 
@@ -45,6 +54,11 @@ pub struct OperatorAirdrop<'info> {
     )]
     pub user_token_account: Box<Account<'info, TokenAccount>>,
 
+    /// CHECK: The RENEC token metadata program
+    #[account(
+        address = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub token_metadata_program: AccountInfo<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
@@ -61,38 +75,16 @@ pub fn handler(ctx: Context<OperatorAirdrop>) -> ProgramResult {
         mint.key(),
         ctx.accounts.user.key()
     );
+    ctx.accounts.config.assert_airdrop_allowed()?;
+    OperatorRole::assert_permission(
+        vault,
+        &ctx.accounts.operator.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::AIRDROPPER,
+        ctx.program_id,
+    )?;
 
-    let (calculated_master_edition, _) = Pubkey::find_program_address(
-        &[
-            METADATA_PREFIX.as_bytes(),
-            TOKEN_METADATA_PROGRAM_ID.as_ref(),
-            mint.key().as_ref(),
-            METADATA_EDITION.as_bytes(),
-        ],
-        &TOKEN_METADATA_PROGRAM_ID,
-    );
-
-    // We just need check master edition to ensure the nft is valid
-    if master_edition.key() != calculated_master_edition {
-        msg!("Invalid master edition account");
-        return Err(InvalidAccountArgument.into());
-    }
-
-    msg!("Master edition is empty {}", master_edition.data_is_empty());
-
-    if master_edition.data_is_empty() {
-        msg!("Master edition account not initialized");
-        return Err(AccountNotInitialized.into());
-    }
-
-    msg!("Check nft success");
-
-    // We just simple check here
-    if mint.decimals != 0 || mint.supply != 1 {
-        return Err(InvalidNftMint.into());
-    }
-
-    msg!("Check nft valid success");
+    assert_valid_voucher_nft(mint, &mint.key(), master_edition)?;
 
     token::transfer(
         CpiContext::new_with_signer(
@@ -106,6 +98,25 @@ pub fn handler(ctx: Context<OperatorAirdrop>) -> ProgramResult {
         ),
         1,
     )?;
+    if vault.soulbound {
+        lock_soulbound_voucher(
+            vault,
+            ctx.accounts.user.to_account_info(),
+            ctx.accounts.user_token_account.to_account_info(),
+            master_edition.to_account_info(),
+            mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        )?;
+    }
+
+    emit!(VoucherAirdropped {
+        vault: vault.key(),
+        mint: mint.key(),
+        recipient: ctx.accounts.user.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    ctx.accounts.vault.withdraw_voucher()?;
 
     msg!("Airdrop nft success");
 
@@ -0,0 +1,33 @@
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        address = config.admin @ OnlyAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ProposeAdmin>, pending_admin: Pubkey) -> ProgramResult {
+    let config = &mut ctx.accounts.config;
+
+    msg!("Propose {} as new admin", pending_admin);
+    config.propose_admin(pending_admin)?;
+
+    emit!(AdminProposed {
+        admin: config.admin,
+        pending_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use crate::constants::*;
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::instructions::burn_voucher;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use solana_program::program::set_return_data;
+
+#[derive(Accounts)]
+pub struct ApplyRepayDiscount<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(mut)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = holder_token_account.mint == mint.key() @ InvalidVoucherHolder,
+        constraint = holder_token_account.owner == holder.key() @ InvalidVoucherHolder,
+    )]
+    pub holder_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [RepayVoucher::SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+    )]
+    pub repay_voucher: Box<Account<'info, RepayVoucher>>,
+
+    /// CHECK: See `RepayVoucher::assert_vault`
+    #[account()]
+    pub metadata_account: AccountInfo<'info>,
+
+    /// CHECK: Token_metadata_program will check this
+    #[account()]
+    pub master_edition: AccountInfo<'info>,
+
+    /// CHECK: The RENEC token metadata program
+    #[account(
+        address = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub token_metadata_program: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Clone)]
+pub struct ApplyRepayDiscountParams {
+    pub amount: u64,
+    pub consume: bool,
+}
+
+pub fn handler(
+    ctx: Context<ApplyRepayDiscount>,
+    params: ApplyRepayDiscountParams,
+) -> ProgramResult {
+    let mint = &ctx.accounts.mint;
+    let vault = &ctx.accounts.vault;
+    let holder = &ctx.accounts.holder;
+    let holder_token_account = &ctx.accounts.holder_token_account;
+    let repay_voucher = &mut ctx.accounts.repay_voucher;
+
+    msg!(
+        "Apply repay voucher {} of holder {} to amount {}",
+        mint.key(),
+        holder.key(),
+        params.amount
+    );
+
+    ctx.accounts.config.assert_redeem_allowed()?;
+    repay_voucher.assert_vault(ctx.program_id, &vault.key(), &ctx.accounts.metadata_account)?;
+
+    if holder_token_account.amount != 1 {
+        msg!("Holder does not own the voucher");
+        return Err(InvalidVoucherHolder.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    repay_voucher.assert_redeemable(current_time)?;
+
+    let discount = if params.consume {
+        repay_voucher.redeem(holder.key(), params.amount, current_time)?
+    } else {
+        repay_voucher.calculate_discount(params.amount)
+    };
+    msg!(
+        "Discount amount {}, remaining amount {}",
+        discount,
+        repay_voucher.remaining_amount()
+    );
+
+    if params.consume {
+        if repay_voucher.is_redeemed() {
+            burn_voucher(
+                vault,
+                holder_token_account,
+                holder.to_account_info(),
+                mint.to_account_info(),
+                ctx.accounts.master_edition.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.token_metadata_program.to_account_info(),
+            )?;
+        }
+
+        emit!(RepayVoucherRedeemed {
+            vault: vault.key(),
+            mint: mint.key(),
+            holder: holder.key(),
+            amount: params.amount,
+            discount,
+            uses_remaining: repay_voucher.uses_remaining,
+            timestamp: current_time,
+        });
+        msg!("Consume repay voucher success");
+    }
+
+    // The calling program reads the discount back with `get_return_data`
+    set_return_data(&discount.to_le_bytes());
+    Ok(())
+}
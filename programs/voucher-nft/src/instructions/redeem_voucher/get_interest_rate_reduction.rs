@@ -0,0 +1,61 @@
+use crate::errors::VoucherNftError::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use solana_program::program::set_return_data;
+
+#[derive(Accounts)]
+pub struct GetInterestRateReduction<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: The holder account address
+    #[account()]
+    pub holder: AccountInfo<'info>,
+
+    #[account()]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        constraint = holder_token_account.mint == mint.key() @ InvalidVoucherHolder,
+        constraint = holder_token_account.owner == holder.key() @ InvalidVoucherHolder,
+    )]
+    pub holder_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [InterestRateVoucher::SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+    )]
+    pub interest_rate_voucher: Box<Account<'info, InterestRateVoucher>>,
+}
+
+// Revoked, redeemed, expired and not yet started vouchers are all rejected the same way
+// as a redeem, so the calling program never mistakes an unusable voucher for a 0 bps one
+pub fn handler(ctx: Context<GetInterestRateReduction>) -> ProgramResult {
+    let interest_rate_voucher = &ctx.accounts.interest_rate_voucher;
+
+    msg!(
+        "Get interest rate reduction of voucher {} for holder {}",
+        ctx.accounts.mint.key(),
+        ctx.accounts.holder.key()
+    );
+    ctx.accounts.config.assert_redeem_allowed()?;
+
+    if ctx.accounts.holder_token_account.amount != 1 {
+        msg!("Holder does not own the voucher");
+        return Err(InvalidVoucherHolder.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    interest_rate_voucher.header.assert_usable(current_time)?;
+
+    let rate_reduction_bps = interest_rate_voucher.rate_reduction_bps;
+    msg!("Rate reduction {} bps", rate_reduction_bps);
+
+    // Returned the same way as the discount of `apply_repay_discount`
+    set_return_data(&rate_reduction_bps.to_le_bytes());
+    Ok(())
+}
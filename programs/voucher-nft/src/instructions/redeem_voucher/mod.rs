@@ -0,0 +1,9 @@
+pub mod apply_repay_discount;
+pub mod get_interest_rate_reduction;
+pub mod redeem_fixed_credit_voucher;
+pub mod redeem_repay_voucher;
+
+pub use apply_repay_discount::*;
+pub use get_interest_rate_reduction::*;
+pub use redeem_fixed_credit_voucher::*;
+pub use redeem_repay_voucher::*;
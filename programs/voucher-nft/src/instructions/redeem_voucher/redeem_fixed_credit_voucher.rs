@@ -0,0 +1,116 @@
+use crate::constants::*;
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::instructions::burn_voucher;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use solana_program::program::set_return_data;
+
+#[derive(Accounts)]
+pub struct RedeemFixedCreditVoucher<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(mut)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = holder_token_account.mint == mint.key() @ InvalidVoucherHolder,
+        constraint = holder_token_account.owner == holder.key() @ InvalidVoucherHolder,
+    )]
+    pub holder_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [FixedCreditVoucher::SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+        constraint = fixed_credit_voucher.header.vault == vault.key() @ InvalidAccountArgument,
+    )]
+    pub fixed_credit_voucher: Box<Account<'info, FixedCreditVoucher>>,
+
+    #[account(
+        address = fixed_credit_voucher.currency_mint @ InvalidCurrencyMint,
+    )]
+    pub currency_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Token_metadata_program will check this
+    #[account()]
+    pub master_edition: AccountInfo<'info>,
+
+    /// CHECK: The RENEC token metadata program
+    #[account(
+        address = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub token_metadata_program: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<RedeemFixedCreditVoucher>, amount: u64) -> ProgramResult {
+    let mint = &ctx.accounts.mint;
+    let vault = &ctx.accounts.vault;
+    let holder = &ctx.accounts.holder;
+    let holder_token_account = &ctx.accounts.holder_token_account;
+    let fixed_credit_voucher = &mut ctx.accounts.fixed_credit_voucher;
+
+    msg!(
+        "Redeem fixed credit voucher {} of holder {} for amount {}",
+        mint.key(),
+        holder.key(),
+        amount
+    );
+
+    ctx.accounts.config.assert_redeem_allowed()?;
+
+    if holder_token_account.amount != 1 {
+        msg!("Holder does not own the voucher");
+        return Err(InvalidVoucherHolder.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let credit = fixed_credit_voucher.redeem(amount, current_time)?;
+    msg!(
+        "Applied credit {}, remaining credit {}",
+        credit,
+        fixed_credit_voucher.remaining_credit
+    );
+
+    if fixed_credit_voucher.is_exhausted() {
+        burn_voucher(
+            vault,
+            holder_token_account,
+            holder.to_account_info(),
+            mint.to_account_info(),
+            ctx.accounts.master_edition.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        )?;
+        msg!("Fixed credit voucher exhausted");
+    }
+
+    emit!(FixedCreditVoucherRedeemed {
+        vault: vault.key(),
+        mint: mint.key(),
+        holder: holder.key(),
+        credit,
+        remaining_credit: fixed_credit_voucher.remaining_credit,
+        timestamp: current_time,
+    });
+
+    // Returned the same way as the discount of `apply_repay_discount`
+    set_return_data(&credit.to_le_bytes());
+    Ok(())
+}
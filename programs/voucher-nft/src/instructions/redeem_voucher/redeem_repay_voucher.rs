@@ -0,0 +1,161 @@
+use crate::constants::*;
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct RedeemRepayVoucher<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(mut)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = holder_token_account.mint == mint.key() @ InvalidVoucherHolder,
+        constraint = holder_token_account.owner == holder.key() @ InvalidVoucherHolder,
+    )]
+    pub holder_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [RepayVoucher::SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+    )]
+    pub repay_voucher: Box<Account<'info, RepayVoucher>>,
+
+    /// CHECK: See `RepayVoucher::assert_vault`
+    #[account()]
+    pub metadata_account: AccountInfo<'info>,
+
+    /// CHECK: Token_metadata_program will check this
+    #[account()]
+    pub master_edition: AccountInfo<'info>,
+
+    /// CHECK: The RENEC token metadata program
+    #[account(
+        address = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub token_metadata_program: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<RedeemRepayVoucher>, amount: u64) -> ProgramResult {
+    let mint = &ctx.accounts.mint;
+    let vault = &ctx.accounts.vault;
+    let holder = &ctx.accounts.holder;
+    let holder_token_account = &ctx.accounts.holder_token_account;
+    let repay_voucher = &mut ctx.accounts.repay_voucher;
+
+    msg!(
+        "Redeem repay voucher {} by holder {} for amount {}",
+        mint.key(),
+        holder.key(),
+        amount
+    );
+
+    ctx.accounts.config.assert_redeem_allowed()?;
+    repay_voucher.assert_vault(ctx.program_id, &vault.key(), &ctx.accounts.metadata_account)?;
+
+    if holder_token_account.amount != 1 {
+        msg!("Holder does not own the voucher");
+        return Err(InvalidVoucherHolder.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let discount = repay_voucher.redeem(holder.key(), amount, current_time)?;
+    msg!(
+        "Record redemption success, discount {}, {} uses remaining",
+        discount,
+        repay_voucher.uses_remaining
+    );
+
+    // Multi-use vouchers stay with the holder until the last use
+    if repay_voucher.is_redeemed() {
+        burn_voucher(
+            vault,
+            holder_token_account,
+            holder.to_account_info(),
+            mint.to_account_info(),
+            ctx.accounts.master_edition.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+        )?;
+    }
+
+    emit!(RepayVoucherRedeemed {
+        vault: vault.key(),
+        mint: mint.key(),
+        holder: holder.key(),
+        amount,
+        discount,
+        uses_remaining: repay_voucher.uses_remaining,
+        timestamp: current_time,
+    });
+    msg!("Redeem repay voucher success");
+    Ok(())
+}
+
+pub(crate) fn burn_voucher<'info>(
+    vault: &Account<'info, Vault>,
+    holder_token_account: &Account<'info, TokenAccount>,
+    holder: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    master_edition: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    token_metadata_program: AccountInfo<'info>,
+) -> ProgramResult {
+    // Soulbound vouchers are frozen with the vault as delegate
+    if holder_token_account.is_frozen()
+        && holder_token_account.delegate == COption::Some(vault.key())
+    {
+        thaw_voucher_account(
+            vault,
+            holder_token_account.to_account_info(),
+            master_edition,
+            mint.clone(),
+            token_program.clone(),
+            token_metadata_program,
+        )?;
+        msg!("Thaw soulbound voucher success");
+    }
+
+    token::burn(
+        CpiContext::new(
+            token_program.clone(),
+            token::Burn {
+                mint,
+                to: holder_token_account.to_account_info(),
+                authority: holder.clone(),
+            },
+        ),
+        1,
+    )?;
+    msg!("Burn voucher success");
+
+    token::close_account(CpiContext::new(
+        token_program,
+        token::CloseAccount {
+            account: holder_token_account.to_account_info(),
+            destination: holder.clone(),
+            authority: holder,
+        },
+    ))
+}
@@ -0,0 +1,55 @@
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevokeOperatorRole<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [
+            OperatorRole::SEED.as_bytes(),
+            vault.key().as_ref(),
+            operator_role.operator.as_ref(),
+        ],
+        bump,
+    )]
+    pub operator_role: Box<Account<'info, OperatorRole>>,
+
+    #[account(
+        address = config.admin @ OnlyAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeOperatorRole>, permissions: u8) -> ProgramResult {
+    let operator_role = &mut ctx.accounts.operator_role;
+
+    msg!(
+        "Revoke permissions {} from operator {}",
+        permissions,
+        operator_role.operator
+    );
+    operator_role.revoke(permissions)?;
+
+    emit!(OperatorRoleRevoked {
+        vault: operator_role.vault,
+        operator: operator_role.operator,
+        revoked: permissions,
+        permissions: operator_role.permissions,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+// Shared by `revoke_voucher` and `unrevoke_voucher`
+#[derive(Accounts)]
+pub struct RevokeVoucher<'info> {
+    #[account(
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account()]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Will check in the program
+    #[account()]
+    pub operator_role: AccountInfo<'info>,
+
+    #[account()]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        constraint = holder_token_account.mint == mint.key() @ InvalidVoucherHolder,
+        constraint = holder_token_account.amount == 1 @ InvalidVoucherHolder,
+    )]
+    pub holder_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: See `RepayVoucher::assert_vault`
+    #[account()]
+    pub metadata_account: AccountInfo<'info>,
+
+    /// CHECK: Repay, interest rate or fixed credit voucher of the mint, will check in the program
+    #[account(mut)]
+    pub voucher_information: AccountInfo<'info>,
+}
+
+// Revocation only flags the voucher information, the holder token account is left
+// untouched and the holder keeps the NFT, every redeem path rejects it while revoked
+pub fn handler(ctx: Context<RevokeVoucher>, revoked: bool) -> ProgramResult {
+    let vault = &ctx.accounts.vault;
+    let holder_token_account = &ctx.accounts.holder_token_account;
+
+    msg!(
+        "Set voucher {} held by {} revoked {}",
+        ctx.accounts.mint.key(),
+        holder_token_account.owner,
+        revoked
+    );
+    OperatorRole::assert_permission(
+        vault,
+        &ctx.accounts.operator.key(),
+        &ctx.accounts.operator_role,
+        OperatorRole::INFORMATION_MANAGER,
+        ctx.program_id,
+    )?;
+
+    set_voucher_revoked(
+        ctx.program_id,
+        &ctx.accounts.voucher_information,
+        &vault.key(),
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.metadata_account,
+        revoked,
+    )?;
+
+    emit!(VoucherRevokeUpdated {
+        vault: vault.key(),
+        mint: ctx.accounts.mint.key(),
+        holder: holder_token_account.owner,
+        revoked,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Set voucher revoked success");
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        address = config.admin @ OnlyAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Clone)]
+pub struct SetPauseParams {
+    pub paused: bool,
+    pub mint_paused: bool,
+    pub airdrop_paused: bool,
+    pub redeem_paused: bool,
+}
+
+pub fn handler(ctx: Context<SetPause>, params: SetPauseParams) -> ProgramResult {
+    msg!(
+        "Set pause: all {}, mint {}, airdrop {}, redeem {}",
+        params.paused,
+        params.mint_paused,
+        params.airdrop_paused,
+        params.redeem_paused
+    );
+    ctx.accounts.config.set_pause(
+        params.paused,
+        params.mint_paused,
+        params.airdrop_paused,
+        params.redeem_paused,
+    )?;
+
+    emit!(PauseUpdated {
+        paused: params.paused,
+        mint_paused: params.mint_paused,
+        airdrop_paused: params.airdrop_paused,
+        redeem_paused: params.redeem_paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetVaultOperator<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        address = config.admin @ OnlyAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetVaultOperator>, operator: Pubkey) -> ProgramResult {
+    let vault = &mut ctx.accounts.vault;
+
+    msg!(
+        "Set operator of vault {} from {} to {}",
+        vault.key(),
+        vault.operator,
+        operator
+    );
+    let previous_operator = vault.operator;
+    vault.set_operator(operator)?;
+
+    emit!(VaultOperatorUpdated {
+        vault: vault.key(),
+        previous_operator,
+        operator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::errors::VoucherNftError::*;
+use crate::events::*;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetVaultSoulbound<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED.as_bytes(), vault.seed.as_bytes()],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        address = config.admin @ OnlyAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetVaultSoulbound>, soulbound: bool) -> ProgramResult {
+    let vault = &mut ctx.accounts.vault;
+
+    msg!("Set soulbound of vault {} to {}", vault.key(), soulbound);
+    vault.set_soulbound(soulbound)?;
+
+    emit!(VaultSoulboundUpdated {
+        vault: vault.key(),
+        soulbound,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
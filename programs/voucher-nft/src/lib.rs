@@ -1,8 +1,11 @@
-mod constants;
-mod errors;
+pub mod constants;
+pub mod errors;
+mod events;
 mod instructions;
 mod states;
+mod utils;
 
+pub use crate::events::*;
 pub use crate::instructions::*;
 pub use crate::states::*;
 
@@ -18,14 +21,65 @@ pub mod voucher_nft {
         initialize::handler(ctx, params)
     }
 
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, pending_admin: Pubkey) -> ProgramResult {
+        propose_admin::handler(ctx, pending_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> ProgramResult {
+        accept_admin::handler(ctx)
+    }
+
+    pub fn set_pause(ctx: Context<SetPause>, params: SetPauseParams) -> ProgramResult {
+        set_pause::handler(ctx, params)
+    }
+
     pub fn add_vault(ctx: Context<AddVault>, seed: String, operator: Pubkey) -> ProgramResult {
         add_vault::handler(ctx, seed, operator)
     }
 
+    pub fn set_vault_operator(ctx: Context<SetVaultOperator>, operator: Pubkey) -> ProgramResult {
+        set_vault_operator::handler(ctx, operator)
+    }
+
+    pub fn set_vault_soulbound(ctx: Context<SetVaultSoulbound>, soulbound: bool) -> ProgramResult {
+        set_vault_soulbound::handler(ctx, soulbound)
+    }
+
+    pub fn close_vault(ctx: Context<CloseVault>) -> ProgramResult {
+        close_vault::handler(ctx)
+    }
+
+    pub fn grant_operator_role(
+        ctx: Context<GrantOperatorRole>,
+        operator: Pubkey,
+        permissions: u8,
+    ) -> ProgramResult {
+        grant_operator_role::handler(ctx, operator, permissions)
+    }
+
+    pub fn revoke_operator_role(
+        ctx: Context<RevokeOperatorRole>,
+        permissions: u8,
+    ) -> ProgramResult {
+        revoke_operator_role::handler(ctx, permissions)
+    }
+
     pub fn mint_voucher(ctx: Context<MintVoucher>, params: MetadataParams) -> ProgramResult {
         mint_voucher::handler(ctx, params)
     }
 
+    pub fn mint_voucher_to(ctx: Context<MintVoucherTo>, params: MetadataParams) -> ProgramResult {
+        mint_voucher_to::handler(ctx, params)
+    }
+
+    pub fn mint_repay_voucher(
+        ctx: Context<MintRepayVoucher>,
+        metadata_params: MetadataParams,
+        repay_voucher_params: AddRepayVoucherParams,
+    ) -> ProgramResult {
+        mint_repay_voucher::handler(ctx, metadata_params, repay_voucher_params)
+    }
+
     pub fn add_voucher_repay_information(
         ctx: Context<AddRepayVoucher>,
         params: AddRepayVoucherParams,
@@ -33,7 +87,91 @@ pub mod voucher_nft {
         add_repay_voucher::handler(ctx, params)
     }
 
+    pub fn add_voucher_interest_information(
+        ctx: Context<AddInterestRateVoucher>,
+        params: AddInterestRateVoucherParams,
+    ) -> ProgramResult {
+        add_interest_rate_voucher::handler(ctx, params)
+    }
+
+    pub fn add_voucher_fixed_credit_information(
+        ctx: Context<AddFixedCreditVoucher>,
+        params: AddFixedCreditVoucherParams,
+    ) -> ProgramResult {
+        add_fixed_credit_voucher::handler(ctx, params)
+    }
+
+    pub fn update_repay_voucher(
+        ctx: Context<UpdateRepayVoucher>,
+        params: UpdateRepayVoucherParams,
+    ) -> ProgramResult {
+        update_repay_voucher::handler(ctx, params)
+    }
+
+    pub fn close_repay_voucher(
+        ctx: Context<CloseRepayVoucher>,
+        burn_vault_voucher: bool,
+    ) -> ProgramResult {
+        close_repay_voucher::handler(ctx, burn_vault_voucher)
+    }
+
     pub fn operator_airdrop(ctx: Context<OperatorAirdrop>) -> ProgramResult {
         operator_airdrop::handler(ctx)
     }
+
+    pub fn batch_operator_airdrop<'info>(
+        ctx: Context<'_, '_, '_, 'info, BatchOperatorAirdrop<'info>>,
+    ) -> ProgramResult {
+        batch_operator_airdrop::handler(ctx)
+    }
+
+    pub fn get_interest_rate_reduction(ctx: Context<GetInterestRateReduction>) -> ProgramResult {
+        get_interest_rate_reduction::handler(ctx)
+    }
+
+    pub fn revoke_voucher(ctx: Context<RevokeVoucher>) -> ProgramResult {
+        revoke_voucher::handler(ctx, true)
+    }
+
+    pub fn unrevoke_voucher(ctx: Context<RevokeVoucher>) -> ProgramResult {
+        revoke_voucher::handler(ctx, false)
+    }
+
+    pub fn create_claim_distributor(
+        ctx: Context<CreateClaimDistributor>,
+        merkle_root: [u8; 32],
+        end_time: i64,
+    ) -> ProgramResult {
+        create_claim_distributor::handler(ctx, merkle_root, end_time)
+    }
+
+    pub fn claim_voucher(ctx: Context<ClaimVoucher>, proof: Vec<[u8; 32]>) -> ProgramResult {
+        claim_voucher::handler(ctx, proof)
+    }
+
+    pub fn claim_with_ticket(ctx: Context<ClaimWithTicket>, ticket: ClaimTicket) -> ProgramResult {
+        claim_with_ticket::handler(ctx, ticket)
+    }
+
+    pub fn clawback(ctx: Context<Clawback>) -> ProgramResult {
+        clawback::handler(ctx)
+    }
+
+    pub fn redeem_repay_voucher(ctx: Context<RedeemRepayVoucher>, amount: u64) -> ProgramResult {
+        redeem_repay_voucher::handler(ctx, amount)
+    }
+
+    pub fn redeem_fixed_credit_voucher(
+        ctx: Context<RedeemFixedCreditVoucher>,
+        amount: u64,
+    ) -> ProgramResult {
+        redeem_fixed_credit_voucher::handler(ctx, amount)
+    }
+
+    pub fn apply_repay_discount(
+        ctx: Context<ApplyRepayDiscount>,
+        params: ApplyRepayDiscountParams,
+    ) -> ProgramResult {
+        apply_repay_discount::handler(ctx, params)
+    }
 }
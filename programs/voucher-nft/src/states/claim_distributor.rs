@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ClaimDistributor {
+    pub vault: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub end_time: i64,
+    pub claimed_count: u64,
+    pub bump: u8,
+    // Funded the distributor rent and receives it back on clawback
+    pub payer: Pubkey,
+    pub _reserve: [u128; 2],
+}
+
+impl ClaimDistributor {
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8 + 1 + 32 + 16 * 2;
+    pub const SEED: &'static str = "CLAIM_DISTRIBUTOR";
+
+    pub fn initialize(
+        &mut self,
+        vault: Pubkey,
+        merkle_root: [u8; 32],
+        end_time: i64,
+        bump: u8,
+        payer: Pubkey,
+    ) -> ProgramResult {
+        self.vault = vault;
+        self.merkle_root = merkle_root;
+        self.end_time = end_time;
+        self.bump = bump;
+        self.payer = payer;
+        Ok(())
+    }
+
+    pub fn is_closed(&self, current_time: i64) -> bool {
+        current_time > self.end_time
+    }
+}
+
+#[account]
+pub struct ClaimStatus {
+    pub distributor: Pubkey,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub claimed_at: i64,
+    pub _reserve: [u128; 2],
+}
+
+impl ClaimStatus {
+    pub const SPACE: usize = 8 + 32 * 3 + 8 + 16 * 2;
+    pub const SEED: &'static str = "CLAIM_STATUS";
+
+    pub fn initialize(
+        &mut self,
+        distributor: Pubkey,
+        user: Pubkey,
+        mint: Pubkey,
+        claimed_at: i64,
+    ) -> ProgramResult {
+        self.distributor = distributor;
+        self.user = user;
+        self.mint = mint;
+        self.claimed_at = claimed_at;
+        Ok(())
+    }
+}
@@ -1,14 +1,21 @@
+use crate::errors::VoucherNftError::*;
 use anchor_lang::prelude::*;
 
 #[account]
 pub struct Config {
     pub admin: Pubkey,
     pub collection: Pubkey,
-    pub _reserve: [u128; 6],
+    pub pending_admin: Pubkey,
+    pub paused: bool,
+    pub mint_paused: bool,
+    pub airdrop_paused: bool,
+    pub redeem_paused: bool,
+    pub _padding: [u8; 12],
+    pub _reserve: [u128; 3],
 }
 
 impl Config {
-    pub const SPACE: usize = 8 + 32 + 32 + 16 * 6;
+    pub const SPACE: usize = 8 + 32 + 32 + 32 + 4 + 12 + 16 * 3;
     pub const SEED: &'static str = "CONFIG";
 
     pub fn initialize(&mut self, admin: Pubkey, collection: Pubkey) -> ProgramResult {
@@ -16,4 +23,50 @@ impl Config {
         self.collection = collection;
         Ok(())
     }
+
+    pub fn propose_admin(&mut self, pending_admin: Pubkey) -> ProgramResult {
+        self.pending_admin = pending_admin;
+        Ok(())
+    }
+
+    pub fn accept_admin(&mut self) -> ProgramResult {
+        self.admin = self.pending_admin;
+        self.pending_admin = Pubkey::default();
+        Ok(())
+    }
+
+    pub fn set_pause(
+        &mut self,
+        paused: bool,
+        mint_paused: bool,
+        airdrop_paused: bool,
+        redeem_paused: bool,
+    ) -> ProgramResult {
+        self.paused = paused;
+        self.mint_paused = mint_paused;
+        self.airdrop_paused = airdrop_paused;
+        self.redeem_paused = redeem_paused;
+        Ok(())
+    }
+
+    pub fn assert_mint_allowed(&self) -> ProgramResult {
+        if self.paused || self.mint_paused {
+            return Err(ProgramPaused.into());
+        }
+        Ok(())
+    }
+
+    pub fn assert_airdrop_allowed(&self) -> ProgramResult {
+        if self.paused || self.airdrop_paused {
+            return Err(ProgramPaused.into());
+        }
+        Ok(())
+    }
+
+    pub fn assert_redeem_allowed(&self) -> ProgramResult {
+        if self.paused || self.redeem_paused {
+            return Err(ProgramPaused.into());
+        }
+        Ok(())
+    }
 }
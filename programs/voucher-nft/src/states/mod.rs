@@ -1,9 +1,15 @@
 mod authorator;
+mod claim_distributor;
 mod config;
+mod operator_role;
+mod ticket_nonce;
 mod vault;
 mod voucher_information;
 
 pub use authorator::*;
+pub use claim_distributor::*;
 pub use config::*;
+pub use operator_role::*;
+pub use ticket_nonce::*;
 pub use vault::*;
 pub use voucher_information::*;
@@ -0,0 +1,85 @@
+use crate::errors::VoucherNftError::*;
+use crate::states::Vault;
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct OperatorRole {
+    pub vault: Pubkey,
+    pub operator: Pubkey,
+    pub permissions: u8,
+    pub bump: u8,
+    pub _reserve: [u128; 4],
+}
+
+impl OperatorRole {
+    pub const SPACE: usize = 8 + 32 * 2 + 1 + 1 + 16 * 4;
+    pub const SEED: &'static str = "OPERATOR_ROLE";
+
+    pub const MINTER: u8 = 1 << 0;
+    pub const INFORMATION_MANAGER: u8 = 1 << 1;
+    pub const AIRDROPPER: u8 = 1 << 2;
+    pub const ALL_PERMISSIONS: u8 = Self::MINTER | Self::INFORMATION_MANAGER | Self::AIRDROPPER;
+
+    pub fn initialize(&mut self, vault: Pubkey, operator: Pubkey, bump: u8) -> ProgramResult {
+        self.vault = vault;
+        self.operator = operator;
+        self.bump = bump;
+        Ok(())
+    }
+
+    pub fn grant(&mut self, permissions: u8) -> ProgramResult {
+        if permissions & !Self::ALL_PERMISSIONS != 0 {
+            return Err(InvalidPermissions.into());
+        }
+        self.permissions |= permissions;
+        Ok(())
+    }
+
+    pub fn revoke(&mut self, permissions: u8) -> ProgramResult {
+        self.permissions &= !permissions;
+        Ok(())
+    }
+
+    pub fn has_permission(&self, permission: u8) -> bool {
+        self.permissions & permission == permission
+    }
+
+    // The vault operator holds every permission, other keys need a role account
+    pub fn assert_permission<'info>(
+        vault: &Account<'info, Vault>,
+        operator: &Pubkey,
+        operator_role: &AccountInfo<'info>,
+        permission: u8,
+        program_id: &Pubkey,
+    ) -> ProgramResult {
+        if vault.operator == *operator {
+            return Ok(());
+        }
+
+        let (calculated_operator_role, _) = Pubkey::find_program_address(
+            &[
+                OperatorRole::SEED.as_bytes(),
+                vault.key().as_ref(),
+                operator.as_ref(),
+            ],
+            program_id,
+        );
+
+        if operator_role.key() != calculated_operator_role {
+            msg!("Invalid operator role account");
+            return Err(InvalidAccountArgument.into());
+        }
+
+        if operator_role.data_is_empty() {
+            msg!("Operator role not granted");
+            return Err(OnlyOperator.into());
+        }
+
+        let role = Account::<OperatorRole>::try_from(operator_role)?;
+        if !role.has_permission(permission) {
+            msg!("Operator lacks permission {}", permission);
+            return Err(OnlyOperator.into());
+        }
+        Ok(())
+    }
+}
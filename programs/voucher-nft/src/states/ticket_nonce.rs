@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct TicketNonce {
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    pub nonce: u64,
+    pub used_at: i64,
+    pub _reserve: [u128; 2],
+}
+
+impl TicketNonce {
+    pub const SPACE: usize = 8 + 32 * 2 + 8 + 8 + 16 * 2;
+    pub const SEED: &'static str = "TICKET_NONCE";
+
+    pub fn initialize(
+        &mut self,
+        vault: Pubkey,
+        recipient: Pubkey,
+        nonce: u64,
+        used_at: i64,
+    ) -> ProgramResult {
+        self.vault = vault;
+        self.recipient = recipient;
+        self.nonce = nonce;
+        self.used_at = used_at;
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::errors::VoucherNftError::*;
 use anchor_lang::prelude::*;
 
 #[account]
@@ -5,17 +6,58 @@ pub struct Vault {
     pub operator: Pubkey,
     pub bump: u8,
     pub seed: String,
-    pub _reserve: [u128; 6],
+    // Number of voucher NFTs the program put in the vault token accounts and has not taken
+    // out yet. NFTs transferred back into a vault token account by hand are not counted, so
+    // `close_vault` may close a vault whose token accounts still hold vouchers
+    pub voucher_count: u64,
+    // Vouchers leaving a soulbound vault are frozen in the recipient token account, the
+    // recipient has to co-sign airdrops since only the owner can approve the vault as delegate
+    pub soulbound: bool,
+    // Vaults created before `voucher_count` existed read false, their counter is meaningless
+    pub voucher_count_tracked: bool,
+    pub _padding: [u8; 6],
+    pub _reserve: [u128; 5],
 }
 
 impl Vault {
-    pub const SPACE: usize = 8 + 32 + 1 + 4 + 32 + 16 * 6;
+    pub const SPACE: usize = 8 + 32 + 1 + 4 + 32 + 8 + 1 + 1 + 6 + 16 * 5;
     pub const SEED: &'static str = "VAULT";
     pub fn initialize(&mut self, operator: Pubkey, seed: String, bump: u8) -> ProgramResult {
         self.operator = operator;
         self.seed = seed;
         self.bump = bump;
+        self.voucher_count_tracked = true;
 
         Ok(())
     }
+
+    pub fn set_operator(&mut self, operator: Pubkey) -> ProgramResult {
+        self.operator = operator;
+        Ok(())
+    }
+
+    pub fn set_soulbound(&mut self, soulbound: bool) -> ProgramResult {
+        self.soulbound = soulbound;
+        Ok(())
+    }
+
+    pub fn deposit_voucher(&mut self) -> ProgramResult {
+        self.voucher_count = self.voucher_count.checked_add(1).ok_or(MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn withdraw_voucher(&mut self) -> ProgramResult {
+        self.withdraw_vouchers(1)
+    }
+
+    pub fn withdraw_vouchers(&mut self, count: u64) -> ProgramResult {
+        // Untracked vaults may withdraw vouchers they never counted
+        if !self.voucher_count_tracked {
+            self.voucher_count = self.voucher_count.saturating_sub(count);
+            return Ok(());
+        }
+
+        self.voucher_count = self.voucher_count.checked_sub(count).ok_or(MathOverflow)?;
+        Ok(())
+    }
 }
@@ -0,0 +1,68 @@
+use crate::errors::VoucherNftError::*;
+use crate::instructions::AddFixedCreditVoucherParams;
+use crate::states::{VoucherHeader, VoucherKind, VoucherStatus};
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct FixedCreditVoucher {
+    pub header: VoucherHeader,
+    // SPL mint the credit is denominated in
+    pub currency_mint: Pubkey,
+    pub credit_amount: u64,
+    pub remaining_credit: u64,
+    pub _reserve: [u128; 4],
+}
+
+impl FixedCreditVoucher {
+    pub const SPACE: usize = 8 + VoucherHeader::SPACE + 32 + 8 * 2 + 16 * 4;
+    pub const SEED: &'static str = "FIXED_CREDIT_VOUCHER";
+
+    pub fn initialize(
+        &mut self,
+        params: &AddFixedCreditVoucherParams,
+        currency_mint: Pubkey,
+        nft_mint: Pubkey,
+        authorator: Pubkey,
+        vault: Pubkey,
+    ) -> ProgramResult {
+        if params.credit_amount == 0 {
+            return Err(InvalidCreditAmount.into());
+        }
+
+        self.header = VoucherHeader::new(
+            VoucherKind::FixedCredit,
+            nft_mint,
+            authorator,
+            vault,
+            params.start_time,
+            params.end_time,
+        )?;
+        self.currency_mint = currency_mint;
+        self.credit_amount = params.credit_amount;
+        self.remaining_credit = params.credit_amount;
+        Ok(())
+    }
+
+    // Returns the credit actually applied, which never exceeds the remaining credit
+    pub fn redeem(
+        &mut self,
+        amount: u64,
+        current_time: i64,
+    ) -> std::result::Result<u64, ProgramError> {
+        self.header.assert_usable(current_time)?;
+        if amount == 0 {
+            return Err(InvalidCreditAmount.into());
+        }
+
+        let applied = amount.min(self.remaining_credit);
+        self.remaining_credit -= applied;
+        if self.remaining_credit == 0 {
+            self.header.status = VoucherStatus::Redeemed;
+        }
+        Ok(applied)
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining_credit == 0
+    }
+}
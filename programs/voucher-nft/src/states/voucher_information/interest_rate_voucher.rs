@@ -0,0 +1,55 @@
+use crate::errors::VoucherNftError::*;
+use crate::states::{VoucherHeader, VoucherKind};
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct InterestRateVoucher {
+    pub header: VoucherHeader,
+    // Base 10000
+    pub rate_reduction_bps: u16,
+    pub _reserve: [u128; 4],
+}
+
+impl InterestRateVoucher {
+    pub const SPACE: usize = 8 + VoucherHeader::SPACE + 2 + 16 * 4;
+    pub const SEED: &'static str = "INTEREST_RATE_VOUCHER";
+    pub const MAX_RATE_REDUCTION_BPS: u16 = 10000;
+    pub const MAX_DURATION: i64 = 365 * 24 * 60 * 60;
+
+    fn assert_data_is_valid(
+        rate_reduction_bps: u16,
+        start_time: i64,
+        end_time: i64,
+    ) -> ProgramResult {
+        if rate_reduction_bps == 0 || rate_reduction_bps > Self::MAX_RATE_REDUCTION_BPS {
+            return Err(InvalidRateReduction.into());
+        }
+
+        if end_time - start_time > Self::MAX_DURATION {
+            return Err(InvalidVoucherDuration.into());
+        }
+        Ok(())
+    }
+
+    pub fn initialize(
+        &mut self,
+        rate_reduction_bps: u16,
+        start_time: i64,
+        end_time: i64,
+        nft_mint: Pubkey,
+        authorator: Pubkey,
+        vault: Pubkey,
+    ) -> ProgramResult {
+        self.header = VoucherHeader::new(
+            VoucherKind::InterestRate,
+            nft_mint,
+            authorator,
+            vault,
+            start_time,
+            end_time,
+        )?;
+        Self::assert_data_is_valid(rate_reduction_bps, start_time, end_time)?;
+        self.rate_reduction_bps = rate_reduction_bps;
+        Ok(())
+    }
+}
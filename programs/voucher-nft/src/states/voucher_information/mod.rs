@@ -1,3 +1,9 @@
+mod fixed_credit_voucher;
+mod interest_rate_voucher;
 mod repay_voucher;
+mod voucher_header;
 
+pub use fixed_credit_voucher::*;
+pub use interest_rate_voucher::*;
 pub use repay_voucher::*;
+pub use voucher_header::*;
@@ -1,4 +1,7 @@
 use crate::errors::VoucherNftError::*;
+use crate::instructions::AddRepayVoucherParams;
+use crate::states::{VoucherHeader, VoucherKind, VoucherStatus};
+use crate::utils::assert_vault_creator;
 use anchor_lang::prelude::*;
 
 #[account]
@@ -10,11 +13,24 @@ pub struct RepayVoucher {
     pub end_time: i64,
     pub nft_mint: Pubkey,
     pub authorator: Pubkey,
-    pub _reserve: [u128; 6],
+    pub redeemed_by: Pubkey,
+    pub redeemed_at: i64,
+    pub vault: Pubkey,
+    pub revoked: bool,
+    // Zero on vouchers registered before multi-use support, those are single-use
+    pub max_uses: u16,
+    pub uses_remaining: u16,
+    pub _padding: [u8; 3],
+    // Discount granted so far, bounded by `maximum_amount`
+    pub amount_used: u64,
+    // Budget left for the next uses, `maximum_amount - amount_used` kept in the account
+    // so clients read it directly. Zero with nothing used on vouchers registered before
+    // it was stored, see `remaining_amount`
+    pub amount_remaining: u64,
 }
 
 impl RepayVoucher {
-    pub const SPACE: usize = 8 + 2 + 4 + 8 * 2 + 32 * 2 + 16 * 6;
+    pub const SPACE: usize = 8 + 2 + 4 + 8 * 2 + 32 * 2 + 32 + 8 + 32 + 1 + 2 * 2 + 3 + 8 + 8;
     pub const SEED: &'static str = "REPAY_VOUCHER";
 
     fn assert_data_is_valid(
@@ -31,33 +47,157 @@ impl RepayVoucher {
             return Err(InvalidMaximumAmount.into());
         }
 
-        if start_time >= end_time {
-            return Err(StartTimeAfterEndTime.into());
-        }
+        VoucherHeader::assert_validity_window(start_time, end_time)
+    }
 
-        let current_time = Clock::get()?.unix_timestamp as i64;
-        if start_time < current_time {
-            return Err(StartTimeBeforeCurrentTime.into());
-        }
+    pub fn initialize(
+        &mut self,
+        params: &AddRepayVoucherParams,
+        nft_mint: Pubkey,
+        authorator: Pubkey,
+        vault: Pubkey,
+    ) -> ProgramResult {
+        Self::assert_data_is_valid(
+            params.discount_percentage,
+            params.maximum_amount,
+            params.start_time,
+            params.end_time,
+        )?;
+        self.discount_percentage = params.discount_percentage;
+        self.maximum_amount = params.maximum_amount;
+        self.amount_remaining = params.maximum_amount as u64;
+        // Zero keeps the encoding of clients predating `max_uses` meaning a single use
+        self.max_uses = params.max_uses.max(1);
+        self.uses_remaining = self.max_uses;
+        self.start_time = params.start_time;
+        self.end_time = params.end_time;
+        self.nft_mint = nft_mint;
+        self.authorator = authorator;
+        self.vault = vault;
         Ok(())
     }
 
-    pub fn initialize(
+    pub fn update(
         &mut self,
         discount_percentage: u16,
         maximum_amount: u32,
         start_time: i64,
         end_time: i64,
-        nft_mint: Pubkey,
-        authorator: Pubkey,
+        current_time: i64,
     ) -> ProgramResult {
-        Self::assert_data_is_valid(discount_percentage, maximum_amount, start_time, end_time)?;
-        self.discount_percentage = discount_percentage;
-        self.maximum_amount = maximum_amount;
-        self.start_time = start_time;
+        if current_time < self.start_time {
+            Self::assert_data_is_valid(discount_percentage, maximum_amount, start_time, end_time)?;
+            self.discount_percentage = discount_percentage;
+            self.maximum_amount = maximum_amount;
+            self.amount_remaining = (maximum_amount as u64).saturating_sub(self.amount_used);
+            self.start_time = start_time;
+            self.end_time = end_time;
+            return Ok(());
+        }
+
+        // Once the voucher is active only its end time can be extended
+        if discount_percentage != self.discount_percentage
+            || maximum_amount != self.maximum_amount
+            || start_time != self.start_time
+        {
+            return Err(VoucherAlreadyActive.into());
+        }
+
+        if end_time < self.end_time {
+            return Err(InvalidEndTime.into());
+        }
         self.end_time = end_time;
-        self.nft_mint = nft_mint;
-        self.authorator = authorator;
         Ok(())
     }
+
+    // `redeemed_at` records the latest use, the voucher is spent once no use remains
+    pub fn is_redeemed(&self) -> bool {
+        self.redeemed_at != 0 && self.uses_remaining == 0
+    }
+
+    // Vouchers registered before `vault` was stored read the default key, those are
+    // matched through the nft metadata creators and the vault is recorded on the way
+    pub fn assert_vault(
+        &mut self,
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        metadata: &AccountInfo,
+    ) -> ProgramResult {
+        if self.vault == *vault {
+            return Ok(());
+        }
+
+        if self.vault != Pubkey::default() {
+            return Err(InvalidAccountArgument.into());
+        }
+
+        assert_vault_creator(program_id, vault, &self.nft_mint, metadata)?;
+        self.vault = *vault;
+        Ok(())
+    }
+
+    pub fn set_revoked(&mut self, revoked: bool) -> ProgramResult {
+        self.revoked = revoked;
+        Ok(())
+    }
+
+    // RepayVoucher predates the common header and keeps its own layout
+    pub fn header(&self) -> VoucherHeader {
+        let status = if self.revoked {
+            VoucherStatus::Revoked
+        } else if self.is_redeemed() {
+            VoucherStatus::Redeemed
+        } else {
+            VoucherStatus::Active
+        };
+        VoucherHeader {
+            kind: VoucherKind::Repay,
+            nft_mint: self.nft_mint,
+            authorator: self.authorator,
+            vault: self.vault,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            status,
+        }
+    }
+
+    pub fn assert_redeemable(&self, current_time: i64) -> ProgramResult {
+        self.header().assert_usable(current_time)
+    }
+
+    pub fn remaining_amount(&self) -> u64 {
+        // The budget can only reach zero through a use, so this is an account from before
+        // `amount_remaining` was stored and the first use backfills it
+        if self.amount_remaining == 0 && self.amount_used == 0 {
+            return self.maximum_amount as u64;
+        }
+        self.amount_remaining
+    }
+
+    pub fn calculate_discount(&self, amount: u64) -> u64 {
+        let discount = amount as u128 * self.discount_percentage as u128 / 10000;
+        discount.min(self.remaining_amount() as u128) as u64
+    }
+
+    // Records one use and returns the discount granted for `amount`
+    pub fn redeem(
+        &mut self,
+        redeemer: Pubkey,
+        amount: u64,
+        current_time: i64,
+    ) -> std::result::Result<u64, ProgramError> {
+        self.assert_redeemable(current_time)?;
+        let discount = self.calculate_discount(amount);
+        self.amount_remaining = self.remaining_amount() - discount;
+        self.amount_used = self.amount_used.checked_add(discount).ok_or(MathOverflow)?;
+        self.redeemed_by = redeemer;
+        self.redeemed_at = current_time;
+        self.uses_remaining = self.uses_remaining.saturating_sub(1);
+
+        // Uses left over once the budget is spent would grant nothing
+        if self.remaining_amount() == 0 {
+            self.uses_remaining = 0;
+        }
+        Ok(discount)
+    }
 }
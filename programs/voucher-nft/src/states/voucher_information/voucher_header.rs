@@ -0,0 +1,119 @@
+use crate::errors::VoucherNftError::*;
+use crate::utils::assert_vault_voucher_nft;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VoucherKind {
+    Repay,
+    InterestRate,
+    FixedCredit,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VoucherStatus {
+    Active,
+    Redeemed,
+    Revoked,
+}
+
+// Fields shared by every voucher kind, each kind stores its own payload next to it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct VoucherHeader {
+    pub kind: VoucherKind,
+    pub nft_mint: Pubkey,
+    pub authorator: Pubkey,
+    pub vault: Pubkey,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub status: VoucherStatus,
+}
+
+impl VoucherHeader {
+    pub const SPACE: usize = 1 + 32 * 3 + 8 * 2 + 1;
+
+    pub fn new(
+        kind: VoucherKind,
+        nft_mint: Pubkey,
+        authorator: Pubkey,
+        vault: Pubkey,
+        start_time: i64,
+        end_time: i64,
+    ) -> std::result::Result<Self, ProgramError> {
+        Self::assert_validity_window(start_time, end_time)?;
+        Ok(Self {
+            kind,
+            nft_mint,
+            authorator,
+            vault,
+            start_time,
+            end_time,
+            status: VoucherStatus::Active,
+        })
+    }
+
+    // Checks shared by every add_* instruction: the nft comes from the vault, and the mint
+    // carries a single voucher kind so the information accounts of the other kinds must
+    // not exist. Returns the authorator
+    pub fn assert_registrable(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        nft_mint: &Pubkey,
+        metadata: &AccountInfo,
+        master_edition: &AccountInfo,
+        other_kinds: &[&AccountInfo],
+    ) -> std::result::Result<Pubkey, ProgramError> {
+        let authorator =
+            assert_vault_voucher_nft(program_id, vault, nft_mint, metadata, master_edition)?;
+
+        if other_kinds.iter().any(|account| !account.data_is_empty()) {
+            msg!("Mint already has voucher information of another kind");
+            return Err(VoucherAlreadyRegistered.into());
+        }
+        Ok(authorator)
+    }
+
+    pub fn assert_validity_window(start_time: i64, end_time: i64) -> ProgramResult {
+        if start_time >= end_time {
+            return Err(StartTimeAfterEndTime.into());
+        }
+
+        let current_time = Clock::get()?.unix_timestamp as i64;
+        if start_time < current_time {
+            return Err(StartTimeBeforeCurrentTime.into());
+        }
+        Ok(())
+    }
+
+    pub fn assert_vault(&self, vault: &Pubkey) -> ProgramResult {
+        if self.vault != *vault {
+            return Err(InvalidAccountArgument.into());
+        }
+        Ok(())
+    }
+
+    // Spent vouchers are burned with their holder token account, so revoke and redeem
+    // fail on that account before a redeemed status could be read here
+    pub fn set_revoked(&mut self, revoked: bool) -> ProgramResult {
+        self.status = if revoked {
+            VoucherStatus::Revoked
+        } else {
+            VoucherStatus::Active
+        };
+        Ok(())
+    }
+
+    pub fn assert_usable(&self, current_time: i64) -> ProgramResult {
+        if self.status == VoucherStatus::Revoked {
+            return Err(VoucherRevoked.into());
+        }
+
+        if current_time < self.start_time {
+            return Err(VoucherNotStarted.into());
+        }
+
+        if current_time > self.end_time {
+            return Err(VoucherExpired.into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,418 @@
+use crate::constants::*;
+use crate::errors::VoucherNftError::*;
+use crate::states::{Authorator, FixedCreditVoucher, InterestRateVoucher, RepayVoucher, Vault};
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token::{self, Mint};
+use mpl_token_metadata::instruction::{freeze_delegated_account, thaw_delegated_account};
+use mpl_token_metadata::state::Metadata;
+use solana_program::program::invoke_signed;
+use solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use solana_program::{ed25519_program, keccak};
+
+pub fn assert_valid_voucher_nft(
+    mint: &Mint,
+    mint_key: &Pubkey,
+    master_edition: &AccountInfo,
+) -> ProgramResult {
+    let (calculated_master_edition, _) = Pubkey::find_program_address(
+        &[
+            METADATA_PREFIX.as_bytes(),
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint_key.as_ref(),
+            METADATA_EDITION.as_bytes(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+
+    // We just need check master edition to ensure the nft is valid
+    if master_edition.key() != calculated_master_edition {
+        msg!("Invalid master edition account");
+        return Err(InvalidAccountArgument.into());
+    }
+
+    if master_edition.data_is_empty() {
+        msg!("Master edition account not initialized");
+        return Err(AccountNotInitialized.into());
+    }
+
+    msg!("Check nft success");
+
+    // We just simple check here
+    if mint.decimals != 0 || mint.supply != 1 {
+        return Err(InvalidNftMint.into());
+    }
+
+    msg!("Check nft valid success");
+    Ok(())
+}
+
+// Shared by every voucher kind: the nft must have a metadata and master edition,
+// and be created by the vault and signed by the authorator. Returns the authorator
+pub fn assert_vault_voucher_nft(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    metadata: &AccountInfo,
+    master_edition: &AccountInfo,
+) -> Result<Pubkey, ProgramError> {
+    assert_metadata_account(mint, metadata)?;
+
+    let (calculated_master_edition, _) = Pubkey::find_program_address(
+        &[
+            METADATA_PREFIX.as_bytes(),
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+            METADATA_EDITION.as_bytes(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+
+    if master_edition.key() != calculated_master_edition {
+        return Err(InvalidAccountArgument.into());
+    }
+
+    if master_edition.data_is_empty() {
+        return Err(AccountNotInitialized.into());
+    }
+    msg!("Check master edition success");
+
+    assert_creators_signed(program_id, vault, metadata)
+}
+
+// Same creator checks without the master edition, for vouchers already registered
+pub fn assert_vault_creator(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    metadata: &AccountInfo,
+) -> Result<Pubkey, ProgramError> {
+    assert_metadata_account(mint, metadata)?;
+    assert_creators_signed(program_id, vault, metadata)
+}
+
+fn assert_metadata_account(mint: &Pubkey, metadata: &AccountInfo) -> ProgramResult {
+    let (calculated_metadata, _) = Pubkey::find_program_address(
+        &[
+            METADATA_PREFIX.as_bytes(),
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+
+    if metadata.key() != calculated_metadata {
+        return Err(InvalidAccountArgument.into());
+    }
+
+    if metadata.data_is_empty() {
+        return Err(AccountNotInitialized.into());
+    }
+    msg!("Check metadata success");
+    Ok(())
+}
+
+fn assert_creators_signed(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    metadata: &AccountInfo,
+) -> Result<Pubkey, ProgramError> {
+    let (authorator, _) = Pubkey::find_program_address(&[Authorator::SEED.as_bytes()], program_id);
+
+    let metadata_account_data = &mut Metadata::from_account_info(metadata)?;
+    let metadata_creators = metadata_account_data.data.creators.clone();
+    match metadata_creators {
+        None => {
+            msg!("Creators is empty");
+            return Err(AuthoratorNotSigned.into());
+        }
+        Some(creators) => {
+            let authorator_creator = creators.iter().find(|c| c.address == authorator);
+            match authorator_creator {
+                None => {
+                    msg!("Authorator not found");
+                    return Err(AuthoratorNotSigned.into());
+                }
+                Some(creator) => {
+                    if creator.verified == false {
+                        msg!("Authorator not verified");
+                        return Err(AuthoratorNotSigned.into());
+                    }
+                    msg!("Verify authorator success");
+                }
+            }
+
+            let vault_creator = creators.iter().find(|c| c.address == *vault);
+            match vault_creator {
+                None => {
+                    msg!("Vault not found");
+                    return Err(VaultNotSigned.into());
+                }
+                Some(creator) => {
+                    if creator.verified == false {
+                        msg!("Vault not verified");
+                        return Err(VaultNotSigned.into());
+                    }
+                    msg!("Verify vault success");
+                }
+            }
+        }
+    }
+
+    msg!("Verify creators success");
+    Ok(authorator)
+}
+
+pub fn claim_leaf(user: &Pubkey, mint: &Pubkey) -> [u8; 32] {
+    keccak::hashv(&[&[MERKLE_LEAF_PREFIX], user.as_ref(), mint.as_ref()]).0
+}
+
+// Pairs are hashed in sorted order so the proof does not need to carry positions
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let mut computed_hash = leaf;
+    for proof_element in proof.iter() {
+        computed_hash = if computed_hash <= *proof_element {
+            keccak::hashv(&[&[MERKLE_NODE_PREFIX], &computed_hash, proof_element]).0
+        } else {
+            keccak::hashv(&[&[MERKLE_NODE_PREFIX], proof_element, &computed_hash]).0
+        };
+    }
+    computed_hash == root
+}
+
+// num_signatures (1) + padding (1) + one Ed25519SignatureOffsets (14)
+const ED25519_HEADER_LEN: usize = 16;
+
+// Checks the instruction right before the current one is an ed25519 precompile
+// instruction verifying `message` signed by `signer`
+pub fn assert_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> ProgramResult {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        msg!("Missing ed25519 instruction");
+        return Err(InvalidTicketSignature.into());
+    }
+
+    let ed25519_ix =
+        load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    if ed25519_ix.program_id != ed25519_program::id() || !ed25519_ix.accounts.is_empty() {
+        msg!("Invalid ed25519 instruction");
+        return Err(InvalidTicketSignature.into());
+    }
+
+    let data = &ed25519_ix.data;
+    if data.len() < ED25519_HEADER_LEN || data[0] != 1 {
+        msg!("Expect exactly one ed25519 signature");
+        return Err(InvalidTicketSignature.into());
+    }
+
+    let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+    let signature_instruction_index = read_u16(4);
+    let public_key_offset = read_u16(6) as usize;
+    let public_key_instruction_index = read_u16(8);
+    let message_data_offset = read_u16(10) as usize;
+    let message_data_size = read_u16(12) as usize;
+    let message_instruction_index = read_u16(14);
+
+    // Signature, public key and message must all live inside the ed25519 instruction
+    if signature_instruction_index != u16::MAX
+        || public_key_instruction_index != u16::MAX
+        || message_instruction_index != u16::MAX
+    {
+        msg!("Ed25519 data must be in the same instruction");
+        return Err(InvalidTicketSignature.into());
+    }
+
+    let signed_public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(InvalidTicketSignature)?;
+    if signed_public_key != signer.as_ref() {
+        msg!("Ticket not signed by {}", signer);
+        return Err(InvalidTicketSignature.into());
+    }
+
+    let signed_message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(InvalidTicketSignature)?;
+    if signed_message != message {
+        msg!("Ticket message mismatch");
+        return Err(InvalidTicketSignature.into());
+    }
+    Ok(())
+}
+
+fn assert_voucher_information_address(
+    program_id: &Pubkey,
+    voucher_information: &AccountInfo,
+    seed: &str,
+    mint: &Pubkey,
+) -> ProgramResult {
+    let (expected, _) = Pubkey::find_program_address(&[seed.as_bytes(), mint.as_ref()], program_id);
+    if voucher_information.key() != expected {
+        msg!("Invalid voucher information account");
+        return Err(InvalidAccountArgument.into());
+    }
+    Ok(())
+}
+
+// Revocation applies to every voucher kind, the kind is read from the account discriminator
+pub fn set_voucher_revoked<'info>(
+    program_id: &Pubkey,
+    voucher_information: &AccountInfo<'info>,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    metadata: &AccountInfo,
+    revoked: bool,
+) -> ProgramResult {
+    let discriminator: [u8; 8] = match voucher_information.try_borrow_data()?.get(..8) {
+        Some(data) => data.try_into().unwrap(),
+        None => return Err(AccountNotInitialized.into()),
+    };
+
+    if discriminator == RepayVoucher::discriminator() {
+        assert_voucher_information_address(
+            program_id,
+            voucher_information,
+            RepayVoucher::SEED,
+            mint,
+        )?;
+        let mut repay_voucher = Account::<RepayVoucher>::try_from(voucher_information)?;
+        repay_voucher.assert_vault(program_id, vault, metadata)?;
+        repay_voucher.set_revoked(revoked)?;
+        return repay_voucher.exit(program_id);
+    }
+
+    if discriminator == InterestRateVoucher::discriminator() {
+        assert_voucher_information_address(
+            program_id,
+            voucher_information,
+            InterestRateVoucher::SEED,
+            mint,
+        )?;
+        let mut interest_rate_voucher =
+            Account::<InterestRateVoucher>::try_from(voucher_information)?;
+        interest_rate_voucher.header.assert_vault(vault)?;
+        interest_rate_voucher.header.set_revoked(revoked)?;
+        return interest_rate_voucher.exit(program_id);
+    }
+
+    if discriminator == FixedCreditVoucher::discriminator() {
+        assert_voucher_information_address(
+            program_id,
+            voucher_information,
+            FixedCreditVoucher::SEED,
+            mint,
+        )?;
+        let mut fixed_credit_voucher =
+            Account::<FixedCreditVoucher>::try_from(voucher_information)?;
+        fixed_credit_voucher.header.assert_vault(vault)?;
+        fixed_credit_voucher.header.set_revoked(revoked)?;
+        return fixed_credit_voucher.exit(program_id);
+    }
+
+    msg!("Account is not voucher information");
+    Err(InvalidAccountArgument.into())
+}
+
+// The mint freeze authority belongs to the master edition once it is created,
+// so the vault can only freeze token accounts it has been approved as delegate on
+pub fn freeze_voucher_account<'info>(
+    vault: &Account<'info, Vault>,
+    token_account: AccountInfo<'info>,
+    master_edition: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    token_metadata_program: AccountInfo<'info>,
+) -> ProgramResult {
+    invoke_signed(
+        &freeze_delegated_account(
+            token_metadata_program.key(),
+            vault.key(),
+            token_account.key(),
+            master_edition.key(),
+            mint.key(),
+        ),
+        &[
+            vault.to_account_info(),
+            token_account,
+            master_edition,
+            mint,
+            token_program,
+            token_metadata_program,
+        ],
+        &[&[Vault::SEED.as_bytes(), vault.seed.as_bytes(), &[vault.bump]]],
+    )
+}
+
+pub fn thaw_voucher_account<'info>(
+    vault: &Account<'info, Vault>,
+    token_account: AccountInfo<'info>,
+    master_edition: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    token_metadata_program: AccountInfo<'info>,
+) -> ProgramResult {
+    invoke_signed(
+        &thaw_delegated_account(
+            token_metadata_program.key(),
+            vault.key(),
+            token_account.key(),
+            master_edition.key(),
+            mint.key(),
+        ),
+        &[
+            vault.to_account_info(),
+            token_account,
+            master_edition,
+            mint,
+            token_program,
+            token_metadata_program,
+        ],
+        &[&[Vault::SEED.as_bytes(), vault.seed.as_bytes(), &[vault.bump]]],
+    )
+}
+
+// Approves the vault as delegate of the recipient token account and freezes it,
+// which needs the recipient signature
+pub fn lock_soulbound_voucher<'info>(
+    vault: &Account<'info, Vault>,
+    recipient: AccountInfo<'info>,
+    recipient_token_account: AccountInfo<'info>,
+    master_edition: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    token_metadata_program: AccountInfo<'info>,
+) -> ProgramResult {
+    if !recipient.is_signer {
+        msg!("Soulbound voucher requires recipient signature");
+        return Err(RecipientNotSigned.into());
+    }
+
+    token::approve(
+        CpiContext::new(
+            token_program.clone(),
+            token::Approve {
+                to: recipient_token_account.clone(),
+                delegate: vault.to_account_info(),
+                authority: recipient,
+            },
+        ),
+        1,
+    )?;
+
+    freeze_voucher_account(
+        vault,
+        recipient_token_account,
+        master_edition,
+        mint,
+        token_program,
+        token_metadata_program,
+    )?;
+    msg!("Lock soulbound voucher success");
+    Ok(())
+}
@@ -0,0 +1,915 @@
+// Each integration test binary only uses part of these helpers
+#![allow(dead_code)]
+
+use anchor_lang::prelude::{AccountInfo, Clock, ProgramError, Pubkey};
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction, InstructionError};
+use anchor_lang::solana_program::program::{get_return_data, invoke};
+use anchor_lang::solana_program::{program_pack::Pack, system_instruction, system_program, sysvar};
+use anchor_lang::{AccountDeserialize, AccountSerialize, InstructionData, ToAccountMetas};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::ed25519_instruction::new_ed25519_instruction;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use spl_associated_token_account::get_associated_token_address;
+use voucher_nft::constants::{METADATA_EDITION, METADATA_PREFIX, TOKEN_METADATA_PROGRAM_ID};
+use voucher_nft::errors::VoucherNftError;
+use voucher_nft::{accounts, instruction};
+use voucher_nft::{
+    AddFixedCreditVoucherParams, AddInterestRateVoucherParams, AddRepayVoucherParams, Authorator,
+    ClaimDistributor, ClaimStatus, ClaimTicket, Config, FixedCreditVoucher, InterestRateVoucher,
+    MetadataParams, OperatorRole, RepayVoucher, SetPauseParams, TicketNonce,
+    UpdateRepayVoucherParams, Vault,
+};
+
+pub const VAULT_SEED: &str = "VAULT_1";
+pub const SOL: u64 = 1_000_000_000;
+
+pub type ProcessResult = Result<(), BanksClientError>;
+
+pub fn config_address() -> Pubkey {
+    Pubkey::find_program_address(&[Config::SEED.as_bytes()], &voucher_nft::ID).0
+}
+
+pub fn authorator_address() -> Pubkey {
+    Pubkey::find_program_address(&[Authorator::SEED.as_bytes()], &voucher_nft::ID).0
+}
+
+pub fn vault_address(seed: &str) -> Pubkey {
+    Pubkey::find_program_address(&[Vault::SEED.as_bytes(), seed.as_bytes()], &voucher_nft::ID).0
+}
+
+pub fn operator_role_address(vault: &Pubkey, operator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            OperatorRole::SEED.as_bytes(),
+            vault.as_ref(),
+            operator.as_ref(),
+        ],
+        &voucher_nft::ID,
+    )
+    .0
+}
+
+pub fn repay_voucher_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[RepayVoucher::SEED.as_bytes(), mint.as_ref()],
+        &voucher_nft::ID,
+    )
+    .0
+}
+
+pub fn interest_rate_voucher_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[InterestRateVoucher::SEED.as_bytes(), mint.as_ref()],
+        &voucher_nft::ID,
+    )
+    .0
+}
+
+pub fn fixed_credit_voucher_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[FixedCreditVoucher::SEED.as_bytes(), mint.as_ref()],
+        &voucher_nft::ID,
+    )
+    .0
+}
+
+pub fn claim_distributor_address(vault: &Pubkey, merkle_root: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            ClaimDistributor::SEED.as_bytes(),
+            vault.as_ref(),
+            merkle_root.as_ref(),
+        ],
+        &voucher_nft::ID,
+    )
+    .0
+}
+
+pub fn claim_status_address(distributor: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            ClaimStatus::SEED.as_bytes(),
+            distributor.as_ref(),
+            mint.as_ref(),
+        ],
+        &voucher_nft::ID,
+    )
+    .0
+}
+
+pub fn ticket_nonce_address(vault: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            TicketNonce::SEED.as_bytes(),
+            vault.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        &voucher_nft::ID,
+    )
+    .0
+}
+
+pub fn metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            METADATA_PREFIX.as_bytes(),
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn master_edition_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            METADATA_PREFIX.as_bytes(),
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+            METADATA_EDITION.as_bytes(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn build_ix(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: voucher_nft::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn claim_with_ticket_ix(ticket_signer: &Pubkey, ticket: ClaimTicket) -> Instruction {
+    let vault = vault_address(VAULT_SEED);
+    build_ix(
+        accounts::ClaimWithTicket {
+            config: config_address(),
+            vault,
+            ticket_signer: *ticket_signer,
+            operator_role: operator_role_address(&vault, ticket_signer),
+            recipient: ticket.recipient,
+            mint: ticket.mint,
+            master_edition: master_edition_address(&ticket.mint),
+            vault_token_account: get_associated_token_address(&vault, &ticket.mint),
+            recipient_token_account: get_associated_token_address(&ticket.recipient, &ticket.mint),
+            ticket_nonce: ticket_nonce_address(&vault, ticket.nonce),
+            instructions_sysvar: sysvar::instructions::ID,
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            associated_token_program: spl_associated_token_account::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::ClaimWithTicket { ticket },
+    )
+}
+
+pub fn ticket_signature_ix(ticket_signer: &Keypair, ticket: &ClaimTicket) -> Instruction {
+    let keypair = ed25519_dalek::Keypair::from_bytes(&ticket_signer.to_bytes()).unwrap();
+    new_ed25519_instruction(&keypair, &ticket.message(&voucher_nft::ID))
+}
+
+pub fn metadata_params() -> MetadataParams {
+    MetadataParams {
+        name: "Repay Voucher".to_string(),
+        symbol: "REPV".to_string(),
+        uri: "https://example.com/repay_voucher.json".to_string(),
+    }
+}
+
+pub fn repay_voucher_params(now: i64) -> AddRepayVoucherParams {
+    AddRepayVoucherParams {
+        discount_percentage: 1000,
+        maximum_amount: 30,
+        max_uses: 1,
+        start_time: now + 100,
+        end_time: now + 1000,
+    }
+}
+
+pub fn return_data_probe_id() -> Pubkey {
+    Pubkey::new_from_array([0xfe; 32])
+}
+
+pub fn return_data_buffer() -> Pubkey {
+    Pubkey::new_from_array([0xfd; 32])
+}
+
+// Stands in for a lending program: forwards the instruction to voucher_nft and
+// stores what it read back with `get_return_data` as [len, data..] in the buffer
+fn return_data_probe(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (buffer, accounts) = accounts.split_first().unwrap();
+    let metas = accounts[..accounts.len() - 1]
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: *account.key,
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    let ix = Instruction {
+        program_id: voucher_nft::ID,
+        accounts: metas,
+        data: data.to_vec(),
+    };
+    invoke(&ix, accounts)?;
+
+    let mut buffer_data = buffer.try_borrow_mut_data()?;
+    buffer_data.fill(0);
+    if let Some((program_id, return_data)) = get_return_data() {
+        assert_eq!(program_id, voucher_nft::ID);
+        buffer_data[0] = return_data.len() as u8;
+        buffer_data[1..=return_data.len()].copy_from_slice(&return_data);
+    }
+    Ok(())
+}
+
+pub fn error_code(error: VoucherNftError) -> u32 {
+    match ProgramError::from(error) {
+        ProgramError::Custom(code) => code,
+        other => panic!("unexpected program error {:?}", other),
+    }
+}
+
+pub fn assert_error(result: ProcessResult, error: VoucherNftError) {
+    let expected = error_code(error);
+    match result {
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        ))) => assert_eq!(code, expected, "expected error code {:#x}", expected),
+        other => panic!("expected error code {:#x}, got {:?}", expected, other),
+    }
+}
+
+pub struct TestEnv {
+    pub context: ProgramTestContext,
+    pub admin: Keypair,
+    pub operator: Keypair,
+    pub collection: Pubkey,
+}
+
+// Program initialized by the payer as admin, with `VAULT_SEED` operated by `operator`
+pub async fn setup() -> TestEnv {
+    // mpl_token_metadata.so is shared with the TypeScript tests
+    std::env::set_var(
+        "BPF_OUT_DIR",
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../../tests"),
+    );
+    let mut program_test = ProgramTest::new(
+        "voucher_nft",
+        voucher_nft::ID,
+        processor!(voucher_nft::entry),
+    );
+    program_test.prefer_bpf(false);
+    program_test.add_program("mpl_token_metadata", TOKEN_METADATA_PROGRAM_ID, None);
+    program_test.add_program(
+        "return_data_probe",
+        return_data_probe_id(),
+        processor!(return_data_probe),
+    );
+    program_test.add_account(
+        return_data_buffer(),
+        Account {
+            lamports: SOL,
+            data: vec![0; 33],
+            owner: return_data_probe_id(),
+            ..Account::default()
+        },
+    );
+    program_test.set_compute_max_units(1_400_000);
+
+    let context = program_test.start_with_context().await;
+    let admin = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+    let mut env = TestEnv {
+        context,
+        admin,
+        operator: Keypair::new(),
+        collection: Pubkey::default(),
+    };
+
+    let operator = env.operator.pubkey();
+    env.transfer_sol(&operator, 10 * SOL).await;
+    env.collection = env.initialize().await.unwrap();
+    env.add_vault(VAULT_SEED, &operator).await.unwrap();
+    env
+}
+
+impl TestEnv {
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> ProcessResult {
+        let blockhash = self.context.banks_client.get_latest_blockhash().await?;
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+    }
+
+    // Runs `ix` as a CPI of the return data probe and returns what the probe read back
+    pub async fn process_return_data(
+        &mut self,
+        ix: Instruction,
+        signers: &[&Keypair],
+    ) -> Result<Vec<u8>, BanksClientError> {
+        let mut accounts = vec![AccountMeta::new(return_data_buffer(), false)];
+        accounts.extend(ix.accounts);
+        accounts.push(AccountMeta::new_readonly(voucher_nft::ID, false));
+        let probe_ix = Instruction {
+            program_id: return_data_probe_id(),
+            accounts,
+            data: ix.data,
+        };
+        self.process(&[probe_ix], signers).await?;
+
+        let buffer = self
+            .context
+            .banks_client
+            .get_account(return_data_buffer())
+            .await
+            .unwrap()
+            .expect("return data buffer not found");
+        let len = buffer.data[0] as usize;
+        Ok(buffer.data[1..=len].to_vec())
+    }
+
+    pub async fn transfer_sol(&mut self, to: &Pubkey, lamports: u64) {
+        let ix = system_instruction::transfer(&self.context.payer.pubkey(), to, lamports);
+        self.process(&[ix], &[]).await.unwrap();
+    }
+
+    pub async fn new_funded_keypair(&mut self) -> Keypair {
+        let keypair = Keypair::new();
+        self.transfer_sol(&keypair.pubkey(), 10 * SOL).await;
+        keypair
+    }
+
+    pub async fn now(&mut self) -> i64 {
+        let clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp
+    }
+
+    // Moves to a new slot first so that retried transactions get a fresh blockhash
+    pub async fn warp_to_timestamp(&mut self, timestamp: i64) {
+        let mut clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        self.context.warp_to_slot(clock.slot + 2).unwrap();
+        clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = timestamp;
+        self.context.set_sysvar(&clock);
+    }
+
+    pub async fn get_account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> T {
+        let account = self
+            .context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .expect("account not found");
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    // Rewrites a program account in place, used to recreate layouts of older versions
+    pub async fn set_account_data<T: AccountSerialize>(&mut self, address: &Pubkey, value: &T) {
+        let mut account = self
+            .context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .expect("account not found");
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        account.data[..data.len()].copy_from_slice(&data);
+        self.context.set_account(address, &account.into());
+    }
+
+    pub async fn account_exists(&mut self, address: &Pubkey) -> bool {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    pub async fn token_balance(&mut self, address: &Pubkey) -> u64 {
+        let account = self
+            .context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .expect("token account not found");
+        spl_token::state::Account::unpack(&account.data)
+            .unwrap()
+            .amount
+    }
+
+    pub async fn create_mint(&mut self, decimals: u8) -> Pubkey {
+        let mint = Keypair::new();
+        let payer = self.context.payer.pubkey();
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        let instructions = [
+            system_instruction::create_account(
+                &payer,
+                &mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_mint(
+                &spl_token::ID,
+                &mint.pubkey(),
+                &payer,
+                None,
+                decimals,
+            )
+            .unwrap(),
+        ];
+        self.process(&instructions, &[&mint]).await.unwrap();
+        mint.pubkey()
+    }
+
+    // An NFT created straight through token metadata, unknown to the program
+    pub async fn create_foreign_nft(&mut self) -> Pubkey {
+        let mint = self.create_mint(0).await;
+        let payer = self.context.payer.pubkey();
+        let token_account = get_associated_token_address(&payer, &mint);
+        let instructions = [
+            spl_associated_token_account::create_associated_token_account(&payer, &payer, &mint),
+            spl_token::instruction::mint_to(&spl_token::ID, &mint, &token_account, &payer, &[], 1)
+                .unwrap(),
+            mpl_token_metadata::instruction::create_metadata_accounts_v2(
+                TOKEN_METADATA_PROGRAM_ID,
+                metadata_address(&mint),
+                mint,
+                payer,
+                payer,
+                payer,
+                "Foreign".to_string(),
+                "FRGN".to_string(),
+                "https://example.com/foreign.json".to_string(),
+                None,
+                0,
+                true,
+                false,
+                None,
+                None,
+            ),
+            mpl_token_metadata::instruction::create_master_edition_v3(
+                TOKEN_METADATA_PROGRAM_ID,
+                master_edition_address(&mint),
+                mint,
+                payer,
+                payer,
+                metadata_address(&mint),
+                payer,
+                Some(0),
+            ),
+        ];
+        self.process(&instructions, &[]).await.unwrap();
+        mint
+    }
+
+    pub async fn initialize(&mut self) -> Result<Pubkey, BanksClientError> {
+        let collection = Keypair::new();
+        let authorator = authorator_address();
+        let ix = build_ix(
+            accounts::Initialize {
+                config: config_address(),
+                authorator,
+                relend_collection: collection.pubkey(),
+                authorator_token_account: get_associated_token_address(
+                    &authorator,
+                    &collection.pubkey(),
+                ),
+                metadata_account: metadata_address(&collection.pubkey()),
+                master_edition: master_edition_address(&collection.pubkey()),
+                admin: self.admin.pubkey(),
+                token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+                token_program: spl_token::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            instruction::Initialize {
+                params: MetadataParams {
+                    name: "Relend Collection".to_string(),
+                    symbol: "RELC".to_string(),
+                    uri: "https://example.com/relend_collection.json".to_string(),
+                },
+            },
+        );
+        self.process(&[ix], &[&collection]).await?;
+        Ok(collection.pubkey())
+    }
+
+    pub async fn add_vault(&mut self, seed: &str, operator: &Pubkey) -> ProcessResult {
+        let ix = build_ix(
+            accounts::AddVault {
+                config: config_address(),
+                vault: vault_address(seed),
+                admin: self.admin.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::AddVault {
+                seed: seed.to_string(),
+                operator: *operator,
+            },
+        );
+        self.process(&[ix], &[]).await
+    }
+
+    pub async fn set_pause(&mut self, params: SetPauseParams) -> ProcessResult {
+        let ix = build_ix(
+            accounts::SetPause {
+                config: config_address(),
+                admin: self.admin.pubkey(),
+            },
+            instruction::SetPause { params },
+        );
+        self.process(&[ix], &[]).await
+    }
+
+    pub fn mint_voucher_accounts(
+        &self,
+        seed: &str,
+        operator: &Pubkey,
+        mint: &Pubkey,
+    ) -> accounts::MintVoucher {
+        let vault = vault_address(seed);
+        accounts::MintVoucher {
+            config: config_address(),
+            vault,
+            authorator: authorator_address(),
+            operator: *operator,
+            operator_role: operator_role_address(&vault, operator),
+            mint: *mint,
+            vault_token_account: get_associated_token_address(&vault, mint),
+            metadata_account: metadata_address(mint),
+            master_edition: master_edition_address(mint),
+            collection_mint: self.collection,
+            collection_metadata_account: metadata_address(&self.collection),
+            collection_master_edition: master_edition_address(&self.collection),
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            system_program: system_program::ID,
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            rent: sysvar::rent::ID,
+        }
+    }
+
+    pub async fn mint_voucher_by(
+        &mut self,
+        seed: &str,
+        operator: &Keypair,
+    ) -> Result<Pubkey, BanksClientError> {
+        let mint = Keypair::new();
+        let ix = build_ix(
+            self.mint_voucher_accounts(seed, &operator.pubkey(), &mint.pubkey()),
+            instruction::MintVoucher {
+                params: metadata_params(),
+            },
+        );
+        self.process(&[ix], &[operator, &mint]).await?;
+        Ok(mint.pubkey())
+    }
+
+    pub async fn mint_voucher(&mut self) -> Pubkey {
+        let operator = Keypair::from_bytes(&self.operator.to_bytes()).unwrap();
+        self.mint_voucher_by(VAULT_SEED, &operator).await.unwrap()
+    }
+
+    pub fn add_repay_voucher_accounts(
+        &self,
+        seed: &str,
+        operator: &Pubkey,
+        mint: &Pubkey,
+    ) -> accounts::AddRepayVoucher {
+        let vault = vault_address(seed);
+        accounts::AddRepayVoucher {
+            config: config_address(),
+            vault,
+            operator: *operator,
+            operator_role: operator_role_address(&vault, operator),
+            mint: *mint,
+            metadata_account: metadata_address(mint),
+            master_edition: master_edition_address(mint),
+            repay_voucher: repay_voucher_address(mint),
+            interest_rate_voucher: interest_rate_voucher_address(mint),
+            fixed_credit_voucher: fixed_credit_voucher_address(mint),
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    pub async fn add_repay_voucher(
+        &mut self,
+        mint: &Pubkey,
+        params: AddRepayVoucherParams,
+    ) -> ProcessResult {
+        let operator = Keypair::from_bytes(&self.operator.to_bytes()).unwrap();
+        let ix = build_ix(
+            self.add_repay_voucher_accounts(VAULT_SEED, &operator.pubkey(), mint),
+            instruction::AddVoucherRepayInformation { params },
+        );
+        self.process(&[ix], &[&operator]).await
+    }
+
+    pub async fn add_interest_rate_voucher(
+        &mut self,
+        mint: &Pubkey,
+        params: AddInterestRateVoucherParams,
+    ) -> ProcessResult {
+        let operator = Keypair::from_bytes(&self.operator.to_bytes()).unwrap();
+        let vault = vault_address(VAULT_SEED);
+        let ix = build_ix(
+            accounts::AddInterestRateVoucher {
+                config: config_address(),
+                vault,
+                operator: operator.pubkey(),
+                operator_role: operator_role_address(&vault, &operator.pubkey()),
+                mint: *mint,
+                metadata_account: metadata_address(mint),
+                master_edition: master_edition_address(mint),
+                interest_rate_voucher: interest_rate_voucher_address(mint),
+                repay_voucher: repay_voucher_address(mint),
+                fixed_credit_voucher: fixed_credit_voucher_address(mint),
+                token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+                system_program: system_program::ID,
+            },
+            instruction::AddVoucherInterestInformation { params },
+        );
+        self.process(&[ix], &[&operator]).await
+    }
+
+    pub async fn add_fixed_credit_voucher(
+        &mut self,
+        mint: &Pubkey,
+        currency_mint: &Pubkey,
+        params: AddFixedCreditVoucherParams,
+    ) -> ProcessResult {
+        let operator = Keypair::from_bytes(&self.operator.to_bytes()).unwrap();
+        let vault = vault_address(VAULT_SEED);
+        let ix = build_ix(
+            accounts::AddFixedCreditVoucher {
+                config: config_address(),
+                vault,
+                operator: operator.pubkey(),
+                operator_role: operator_role_address(&vault, &operator.pubkey()),
+                mint: *mint,
+                metadata_account: metadata_address(mint),
+                master_edition: master_edition_address(mint),
+                currency_mint: *currency_mint,
+                fixed_credit_voucher: fixed_credit_voucher_address(mint),
+                repay_voucher: repay_voucher_address(mint),
+                interest_rate_voucher: interest_rate_voucher_address(mint),
+                token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+                system_program: system_program::ID,
+            },
+            instruction::AddVoucherFixedCreditInformation { params },
+        );
+        self.process(&[ix], &[&operator]).await
+    }
+
+    pub fn operator_airdrop_ix(&self, mint: &Pubkey, user: &Pubkey) -> Instruction {
+        let vault = vault_address(VAULT_SEED);
+        build_ix(
+            accounts::OperatorAirdrop {
+                config: config_address(),
+                vault,
+                operator: self.operator.pubkey(),
+                operator_role: operator_role_address(&vault, &self.operator.pubkey()),
+                user: *user,
+                mint: *mint,
+                master_edition: master_edition_address(mint),
+                vault_token_account: get_associated_token_address(&vault, mint),
+                user_token_account: get_associated_token_address(user, mint),
+                token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+                associated_token_program: spl_associated_token_account::ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            instruction::OperatorAirdrop {},
+        )
+    }
+
+    pub async fn operator_airdrop(&mut self, mint: &Pubkey, user: &Pubkey) -> ProcessResult {
+        let operator = Keypair::from_bytes(&self.operator.to_bytes()).unwrap();
+        let ix = self.operator_airdrop_ix(mint, user);
+        self.process(&[ix], &[&operator]).await
+    }
+
+    // A plain token transfer, the program never sees the voucher come back
+    pub async fn return_voucher_to_vault(&mut self, holder: &Keypair, mint: &Pubkey) {
+        let ix = spl_token::instruction::transfer(
+            &spl_token::ID,
+            &get_associated_token_address(&holder.pubkey(), mint),
+            &get_associated_token_address(&vault_address(VAULT_SEED), mint),
+            &holder.pubkey(),
+            &[],
+            1,
+        )
+        .unwrap();
+        self.process(&[ix], &[holder]).await.unwrap();
+    }
+
+    pub fn close_vault_ix(&self) -> Instruction {
+        build_ix(
+            accounts::CloseVault {
+                config: config_address(),
+                vault: vault_address(VAULT_SEED),
+                admin: self.admin.pubkey(),
+            },
+            instruction::CloseVault {},
+        )
+    }
+
+    pub async fn set_vault_soulbound(&mut self, soulbound: bool) -> ProcessResult {
+        let ix = build_ix(
+            accounts::SetVaultSoulbound {
+                config: config_address(),
+                vault: vault_address(VAULT_SEED),
+                admin: self.admin.pubkey(),
+            },
+            instruction::SetVaultSoulbound { soulbound },
+        );
+        self.process(&[ix], &[]).await
+    }
+
+    pub fn redeem_repay_voucher_accounts(
+        &self,
+        holder: &Pubkey,
+        mint: &Pubkey,
+    ) -> accounts::RedeemRepayVoucher {
+        accounts::RedeemRepayVoucher {
+            config: config_address(),
+            vault: vault_address(VAULT_SEED),
+            holder: *holder,
+            mint: *mint,
+            holder_token_account: get_associated_token_address(holder, mint),
+            repay_voucher: repay_voucher_address(mint),
+            metadata_account: metadata_address(mint),
+            master_edition: master_edition_address(mint),
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            token_program: spl_token::ID,
+        }
+    }
+
+    pub async fn redeem_repay_voucher(
+        &mut self,
+        holder: &Keypair,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> ProcessResult {
+        let ix = build_ix(
+            self.redeem_repay_voucher_accounts(&holder.pubkey(), mint),
+            instruction::RedeemRepayVoucher { amount },
+        );
+        self.process(&[ix], &[holder]).await
+    }
+
+    pub async fn update_repay_voucher(
+        &mut self,
+        mint: &Pubkey,
+        params: UpdateRepayVoucherParams,
+    ) -> ProcessResult {
+        let operator = Keypair::from_bytes(&self.operator.to_bytes()).unwrap();
+        let vault = vault_address(VAULT_SEED);
+        let ix = build_ix(
+            accounts::UpdateRepayVoucher {
+                vault,
+                operator: operator.pubkey(),
+                operator_role: operator_role_address(&vault, &operator.pubkey()),
+                metadata_account: metadata_address(mint),
+                repay_voucher: repay_voucher_address(mint),
+            },
+            instruction::UpdateRepayVoucher { params },
+        );
+        self.process(&[ix], &[&operator]).await
+    }
+
+    pub async fn close_repay_voucher(
+        &mut self,
+        mint: &Pubkey,
+        burn_vault_voucher: bool,
+    ) -> ProcessResult {
+        let operator = Keypair::from_bytes(&self.operator.to_bytes()).unwrap();
+        let vault = vault_address(VAULT_SEED);
+        let ix = build_ix(
+            accounts::CloseRepayVoucher {
+                vault,
+                operator: operator.pubkey(),
+                operator_role: operator_role_address(&vault, &operator.pubkey()),
+                mint: *mint,
+                vault_token_account: get_associated_token_address(&vault, mint),
+                metadata_account: metadata_address(mint),
+                repay_voucher: repay_voucher_address(mint),
+                token_program: spl_token::ID,
+            },
+            instruction::CloseRepayVoucher { burn_vault_voucher },
+        );
+        self.process(&[ix], &[&operator]).await
+    }
+
+    pub fn revoke_voucher_accounts(
+        &self,
+        voucher_information: &Pubkey,
+        holder: &Pubkey,
+        mint: &Pubkey,
+    ) -> accounts::RevokeVoucher {
+        let vault = vault_address(VAULT_SEED);
+        accounts::RevokeVoucher {
+            vault,
+            operator: self.operator.pubkey(),
+            operator_role: operator_role_address(&vault, &self.operator.pubkey()),
+            mint: *mint,
+            holder_token_account: get_associated_token_address(holder, mint),
+            metadata_account: metadata_address(mint),
+            voucher_information: *voucher_information,
+        }
+    }
+
+    // Any voucher kind is revoked through its information account
+    pub async fn revoke_voucher(
+        &mut self,
+        voucher_information: &Pubkey,
+        holder: &Pubkey,
+        mint: &Pubkey,
+    ) -> ProcessResult {
+        let operator = Keypair::from_bytes(&self.operator.to_bytes()).unwrap();
+        let ix = build_ix(
+            self.revoke_voucher_accounts(voucher_information, holder, mint),
+            instruction::RevokeVoucher {},
+        );
+        self.process(&[ix], &[&operator]).await
+    }
+
+    pub async fn unrevoke_voucher(
+        &mut self,
+        voucher_information: &Pubkey,
+        holder: &Pubkey,
+        mint: &Pubkey,
+    ) -> ProcessResult {
+        let operator = Keypair::from_bytes(&self.operator.to_bytes()).unwrap();
+        let ix = build_ix(
+            self.revoke_voucher_accounts(voucher_information, holder, mint),
+            instruction::UnrevokeVoucher {},
+        );
+        self.process(&[ix], &[&operator]).await
+    }
+
+    pub async fn create_claim_distributor(
+        &mut self,
+        merkle_root: [u8; 32],
+        end_time: i64,
+    ) -> ProcessResult {
+        let operator = Keypair::from_bytes(&self.operator.to_bytes()).unwrap();
+        let vault = vault_address(VAULT_SEED);
+        let ix = build_ix(
+            accounts::CreateClaimDistributor {
+                config: config_address(),
+                vault,
+                operator: operator.pubkey(),
+                operator_role: operator_role_address(&vault, &operator.pubkey()),
+                distributor: claim_distributor_address(&vault, &merkle_root),
+                system_program: system_program::ID,
+            },
+            instruction::CreateClaimDistributor {
+                merkle_root,
+                end_time,
+            },
+        );
+        self.process(&[ix], &[&operator]).await
+    }
+
+    // Mints a voucher with repay information and airdrops it to a fresh funded user
+    pub async fn airdropped_repay_voucher(&mut self) -> (Pubkey, Keypair) {
+        let now = self.now().await;
+        let mint = self.mint_voucher().await;
+        self.add_repay_voucher(&mint, repay_voucher_params(now))
+            .await
+            .unwrap();
+        let user = self.new_funded_keypair().await;
+        self.operator_airdrop(&mint, &user.pubkey()).await.unwrap();
+        (mint, user)
+    }
+}
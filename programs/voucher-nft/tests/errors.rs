@@ -0,0 +1,685 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_lang::AnchorSerialize;
+use common::*;
+use solana_sdk::ed25519_instruction::new_ed25519_instruction;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+use voucher_nft::constants::TOKEN_METADATA_PROGRAM_ID;
+use voucher_nft::errors::VoucherNftError::*;
+use voucher_nft::{accounts, instruction};
+use voucher_nft::{
+    AddFixedCreditVoucherParams, AddInterestRateVoucherParams, AddRepayVoucherParams, ClaimTicket,
+    FixedCreditVoucher, InterestRateVoucher, RepayVoucher, SetPauseParams,
+    UpdateRepayVoucherParams, VoucherStatus,
+};
+
+async fn add_repay_voucher_error(
+    update: impl FnOnce(&mut AddRepayVoucherParams, i64),
+) -> ProcessResult {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let mut params = repay_voucher_params(now);
+    update(&mut params, now);
+    env.add_repay_voucher(&mint, params).await
+}
+
+fn redeem_fixed_credit_voucher_ix(
+    holder: &Pubkey,
+    mint: &Pubkey,
+    currency_mint: &Pubkey,
+) -> Instruction {
+    build_ix(
+        accounts::RedeemFixedCreditVoucher {
+            config: config_address(),
+            vault: vault_address(VAULT_SEED),
+            holder: *holder,
+            mint: *mint,
+            holder_token_account: get_associated_token_address(holder, mint),
+            fixed_credit_voucher: fixed_credit_voucher_address(mint),
+            currency_mint: *currency_mint,
+            master_edition: master_edition_address(mint),
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            token_program: spl_token::ID,
+        },
+        instruction::RedeemFixedCreditVoucher { amount: 100 },
+    )
+}
+
+#[tokio::test]
+async fn only_admin() {
+    let mut env = setup().await;
+    let attacker = env.new_funded_keypair().await;
+    let ix = build_ix(
+        accounts::AddVault {
+            config: config_address(),
+            vault: vault_address("VAULT_2"),
+            admin: attacker.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::AddVault {
+            seed: "VAULT_2".to_string(),
+            operator: attacker.pubkey(),
+        },
+    );
+    assert_error(env.process(&[ix], &[&attacker]).await, OnlyAdmin);
+}
+
+#[tokio::test]
+async fn only_pending_admin() {
+    let mut env = setup().await;
+    let attacker = env.new_funded_keypair().await;
+    let ix = build_ix(
+        accounts::AcceptAdmin {
+            config: config_address(),
+            pending_admin: attacker.pubkey(),
+        },
+        instruction::AcceptAdmin {},
+    );
+    assert_error(env.process(&[ix], &[&attacker]).await, OnlyPendingAdmin);
+}
+
+#[tokio::test]
+async fn program_paused() {
+    let mut env = setup().await;
+    env.set_pause(SetPauseParams {
+        paused: false,
+        mint_paused: true,
+        airdrop_paused: false,
+        redeem_paused: false,
+    })
+    .await
+    .unwrap();
+
+    let operator = Keypair::from_bytes(&env.operator.to_bytes()).unwrap();
+    let result = env.mint_voucher_by(VAULT_SEED, &operator).await.map(|_| ());
+    assert_error(result, ProgramPaused);
+}
+
+#[tokio::test]
+async fn vault_not_empty() {
+    let mut env = setup().await;
+    env.mint_voucher().await;
+    let ix = env.close_vault_ix();
+    assert_error(env.process(&[ix], &[]).await, VaultNotEmpty);
+}
+
+#[tokio::test]
+async fn math_overflow() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    env.return_voucher_to_vault(&user, &mint).await;
+
+    // The vault never counted the returned voucher, so it cannot hand it out again
+    let other_user = Pubkey::new_unique();
+    assert_error(env.operator_airdrop(&mint, &other_user).await, MathOverflow);
+}
+
+#[tokio::test]
+async fn invalid_permissions() {
+    let mut env = setup().await;
+    let operator = Pubkey::new_unique();
+    let vault = vault_address(VAULT_SEED);
+    let ix = build_ix(
+        accounts::GrantOperatorRole {
+            config: config_address(),
+            vault,
+            operator_role: operator_role_address(&vault, &operator),
+            admin: env.admin.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::GrantOperatorRole {
+            operator,
+            permissions: 0x80,
+        },
+    );
+    assert_error(env.process(&[ix], &[]).await, InvalidPermissions);
+}
+
+#[tokio::test]
+async fn only_operator() {
+    let mut env = setup().await;
+    let attacker = env.new_funded_keypair().await;
+    let result = env.mint_voucher_by(VAULT_SEED, &attacker).await.map(|_| ());
+    assert_error(result, OnlyOperator);
+}
+
+#[tokio::test]
+async fn invalid_discount_percentage() {
+    let result = add_repay_voucher_error(|p, _| p.discount_percentage = 10001).await;
+    assert_error(result, InvalidDiscountPercentage);
+}
+
+#[tokio::test]
+async fn invalid_maximum_amount() {
+    let result = add_repay_voucher_error(|p, _| p.maximum_amount = 0).await;
+    assert_error(result, InvalidMaximumAmount);
+}
+
+#[tokio::test]
+async fn start_time_after_end_time() {
+    let result = add_repay_voucher_error(|p, _| p.end_time = p.start_time).await;
+    assert_error(result, StartTimeAfterEndTime);
+}
+
+#[tokio::test]
+async fn start_time_before_current_time() {
+    let result = add_repay_voucher_error(|p, now| p.start_time = now - 1).await;
+    assert_error(result, StartTimeBeforeCurrentTime);
+}
+
+#[tokio::test]
+async fn invalid_account_argument() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let operator = Keypair::from_bytes(&env.operator.to_bytes()).unwrap();
+    let mut accounts = env.add_repay_voucher_accounts(VAULT_SEED, &operator.pubkey(), &mint);
+    accounts.metadata_account = metadata_address(&env.collection);
+    let ix = build_ix(
+        accounts,
+        instruction::AddVoucherRepayInformation {
+            params: repay_voucher_params(now),
+        },
+    );
+    assert_error(
+        env.process(&[ix], &[&operator]).await,
+        InvalidAccountArgument,
+    );
+}
+
+#[tokio::test]
+async fn account_not_initialized() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.create_mint(0).await;
+    let result = env
+        .add_repay_voucher(&mint, repay_voucher_params(now))
+        .await;
+    assert_error(result, AccountNotInitialized);
+}
+
+#[tokio::test]
+async fn authorator_not_signed() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.create_foreign_nft().await;
+    let result = env
+        .add_repay_voucher(&mint, repay_voucher_params(now))
+        .await;
+    assert_error(result, AuthoratorNotSigned);
+}
+
+#[tokio::test]
+async fn vault_not_signed() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let other_operator = env.new_funded_keypair().await;
+    env.add_vault("VAULT_2", &other_operator.pubkey())
+        .await
+        .unwrap();
+    let mint = env.mint_voucher().await;
+
+    // A voucher of the first vault registered through the second one
+    let ix = build_ix(
+        env.add_repay_voucher_accounts("VAULT_2", &other_operator.pubkey(), &mint),
+        instruction::AddVoucherRepayInformation {
+            params: repay_voucher_params(now),
+        },
+    );
+    assert_error(env.process(&[ix], &[&other_operator]).await, VaultNotSigned);
+}
+
+#[tokio::test]
+async fn invalid_nft_mint() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    env.warp_to_timestamp(repay_voucher.start_time).await;
+    env.redeem_repay_voucher(&user, &mint, 100).await.unwrap();
+
+    // The burned voucher leaves a mint without supply behind
+    let other_user = Pubkey::new_unique();
+    assert_error(
+        env.operator_airdrop(&mint, &other_user).await,
+        InvalidNftMint,
+    );
+}
+
+#[tokio::test]
+async fn voucher_not_started() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    assert_error(
+        env.redeem_repay_voucher(&user, &mint, 100).await,
+        VoucherNotStarted,
+    );
+}
+
+#[tokio::test]
+async fn voucher_expired() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    env.warp_to_timestamp(repay_voucher.end_time + 1).await;
+    assert_error(
+        env.redeem_repay_voucher(&user, &mint, 100).await,
+        VoucherExpired,
+    );
+}
+
+#[tokio::test]
+async fn invalid_voucher_holder() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    let attacker = env.new_funded_keypair().await;
+    let mut accounts = env.redeem_repay_voucher_accounts(&attacker.pubkey(), &mint);
+    accounts.holder_token_account = get_associated_token_address(&user.pubkey(), &mint);
+    let ix = build_ix(accounts, instruction::RedeemRepayVoucher { amount: 100 });
+    assert_error(env.process(&[ix], &[&attacker]).await, InvalidVoucherHolder);
+}
+
+#[tokio::test]
+async fn voucher_revoked() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    env.revoke_voucher(&repay_voucher_address(&mint), &user.pubkey(), &mint)
+        .await
+        .unwrap();
+
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    env.warp_to_timestamp(repay_voucher.start_time).await;
+    assert_error(
+        env.redeem_repay_voucher(&user, &mint, 100).await,
+        VoucherRevoked,
+    );
+}
+
+#[tokio::test]
+async fn voucher_already_active() {
+    let mut env = setup().await;
+    let (mint, _) = env.airdropped_repay_voucher().await;
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    env.warp_to_timestamp(repay_voucher.start_time).await;
+
+    let params = UpdateRepayVoucherParams {
+        discount_percentage: repay_voucher.discount_percentage + 1,
+        maximum_amount: repay_voucher.maximum_amount,
+        start_time: repay_voucher.start_time,
+        end_time: repay_voucher.end_time,
+    };
+    assert_error(
+        env.update_repay_voucher(&mint, params).await,
+        VoucherAlreadyActive,
+    );
+}
+
+#[tokio::test]
+async fn invalid_end_time() {
+    let mut env = setup().await;
+    let (mint, _) = env.airdropped_repay_voucher().await;
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    env.warp_to_timestamp(repay_voucher.start_time).await;
+
+    let params = UpdateRepayVoucherParams {
+        discount_percentage: repay_voucher.discount_percentage,
+        maximum_amount: repay_voucher.maximum_amount,
+        start_time: repay_voucher.start_time,
+        end_time: repay_voucher.end_time - 1,
+    };
+    assert_error(
+        env.update_repay_voucher(&mint, params).await,
+        InvalidEndTime,
+    );
+}
+
+#[tokio::test]
+async fn voucher_not_closable() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    env.add_repay_voucher(&mint, repay_voucher_params(now))
+        .await
+        .unwrap();
+
+    assert_error(
+        env.close_repay_voucher(&mint, true).await,
+        VoucherNotClosable,
+    );
+}
+
+#[tokio::test]
+async fn recipient_not_signed() {
+    let mut env = setup().await;
+    env.set_vault_soulbound(true).await.unwrap();
+
+    let operator = Keypair::from_bytes(&env.operator.to_bytes()).unwrap();
+    let mint = Keypair::new();
+    let recipient = Pubkey::new_unique();
+    let base = env.mint_voucher_accounts(VAULT_SEED, &operator.pubkey(), &mint.pubkey());
+    let ix = build_ix(
+        accounts::MintVoucherTo {
+            config: base.config,
+            vault: base.vault,
+            authorator: base.authorator,
+            operator: base.operator,
+            operator_role: base.operator_role,
+            mint: base.mint,
+            recipient,
+            recipient_token_account: get_associated_token_address(&recipient, &mint.pubkey()),
+            metadata_account: base.metadata_account,
+            master_edition: base.master_edition,
+            collection_mint: base.collection_mint,
+            collection_metadata_account: base.collection_metadata_account,
+            collection_master_edition: base.collection_master_edition,
+            token_metadata_program: base.token_metadata_program,
+            system_program: base.system_program,
+            token_program: base.token_program,
+            associated_token_program: base.associated_token_program,
+            rent: base.rent,
+        },
+        instruction::MintVoucherTo {
+            params: metadata_params(),
+        },
+    );
+    assert_error(
+        env.process(&[ix], &[&operator, &mint]).await,
+        RecipientNotSigned,
+    );
+}
+
+#[tokio::test]
+async fn invalid_merkle_proof() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let merkle_root = [7u8; 32];
+    env.create_claim_distributor(merkle_root, now + 1000)
+        .await
+        .unwrap();
+    let mint = env.mint_voucher().await;
+    let user = env.new_funded_keypair().await;
+
+    let vault = vault_address(VAULT_SEED);
+    let distributor = claim_distributor_address(&vault, &merkle_root);
+    let ix = build_ix(
+        accounts::ClaimVoucher {
+            config: config_address(),
+            vault,
+            distributor,
+            user: user.pubkey(),
+            mint,
+            master_edition: master_edition_address(&mint),
+            vault_token_account: get_associated_token_address(&vault, &mint),
+            user_token_account: get_associated_token_address(&user.pubkey(), &mint),
+            claim_status: claim_status_address(&distributor, &mint),
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            associated_token_program: spl_associated_token_account::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::ClaimVoucher { proof: vec![] },
+    );
+    assert_error(env.process(&[ix], &[&user]).await, InvalidMerkleProof);
+}
+
+#[tokio::test]
+async fn claim_window_closed() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    assert_error(
+        env.create_claim_distributor([7u8; 32], now).await,
+        ClaimWindowClosed,
+    );
+}
+
+#[tokio::test]
+async fn claim_window_not_closed() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let merkle_root = [7u8; 32];
+    env.create_claim_distributor(merkle_root, now + 1000)
+        .await
+        .unwrap();
+
+    let operator = Keypair::from_bytes(&env.operator.to_bytes()).unwrap();
+    let vault = vault_address(VAULT_SEED);
+    let ix = build_ix(
+        accounts::Clawback {
+            vault,
+            operator: operator.pubkey(),
+            operator_role: operator_role_address(&vault, &operator.pubkey()),
+            distributor: claim_distributor_address(&vault, &merkle_root),
+            payer: operator.pubkey(),
+        },
+        instruction::Clawback {},
+    );
+    assert_error(env.process(&[ix], &[&operator]).await, ClaimWindowNotClosed);
+}
+
+#[tokio::test]
+async fn invalid_ticket_signature() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let recipient = env.new_funded_keypair().await;
+    let ticket = ClaimTicket {
+        vault: vault_address(VAULT_SEED),
+        mint,
+        recipient: recipient.pubkey(),
+        expiry: now + 1000,
+        nonce: 1,
+    };
+
+    // A well formed ed25519 signature, but not from the vault operator
+    let forger = ed25519_dalek::Keypair::from_bytes(&Keypair::new().to_bytes()).unwrap();
+    let ed25519_ix = new_ed25519_instruction(&forger, &ticket.message(&voucher_nft::ID));
+    let ix = claim_with_ticket_ix(&env.operator.pubkey(), ticket);
+    assert_error(
+        env.process(&[ed25519_ix, ix], &[&recipient]).await,
+        InvalidTicketSignature,
+    );
+}
+
+#[tokio::test]
+async fn invalid_ticket_signature_without_tag() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let recipient = env.new_funded_keypair().await;
+    let ticket = ClaimTicket {
+        vault: vault_address(VAULT_SEED),
+        mint,
+        recipient: recipient.pubkey(),
+        expiry: now + 1000,
+        nonce: 1,
+    };
+
+    // The operator signed the bare ticket, without the tag and program id
+    let operator = ed25519_dalek::Keypair::from_bytes(&env.operator.to_bytes()).unwrap();
+    let ed25519_ix = new_ed25519_instruction(&operator, &ticket.try_to_vec().unwrap());
+    let ix = claim_with_ticket_ix(&env.operator.pubkey(), ticket);
+    assert_error(
+        env.process(&[ed25519_ix, ix], &[&recipient]).await,
+        InvalidTicketSignature,
+    );
+}
+
+#[tokio::test]
+async fn ticket_expired() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let recipient = env.new_funded_keypair().await;
+    let ticket = ClaimTicket {
+        vault: vault_address(VAULT_SEED),
+        mint,
+        recipient: recipient.pubkey(),
+        expiry: now - 1,
+        nonce: 1,
+    };
+    let ix = claim_with_ticket_ix(&env.operator.pubkey(), ticket);
+    assert_error(env.process(&[ix], &[&recipient]).await, TicketExpired);
+}
+
+#[tokio::test]
+async fn invalid_rate_reduction() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let params = AddInterestRateVoucherParams {
+        rate_reduction_bps: 0,
+        start_time: now + 100,
+        end_time: now + 1000,
+    };
+    assert_error(
+        env.add_interest_rate_voucher(&mint, params).await,
+        InvalidRateReduction,
+    );
+}
+
+#[tokio::test]
+async fn invalid_voucher_duration() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let params = AddInterestRateVoucherParams {
+        rate_reduction_bps: 100,
+        start_time: now + 100,
+        end_time: now + 101 + InterestRateVoucher::MAX_DURATION,
+    };
+    assert_error(
+        env.add_interest_rate_voucher(&mint, params).await,
+        InvalidVoucherDuration,
+    );
+}
+
+#[tokio::test]
+async fn voucher_already_registered() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    env.add_repay_voucher(&mint, repay_voucher_params(now))
+        .await
+        .unwrap();
+
+    let params = AddInterestRateVoucherParams {
+        rate_reduction_bps: 100,
+        start_time: now + 100,
+        end_time: now + 1000,
+    };
+    assert_error(
+        env.add_interest_rate_voucher(&mint, params).await,
+        VoucherAlreadyRegistered,
+    );
+    let currency_mint = env.create_mint(6).await;
+    let params = AddFixedCreditVoucherParams {
+        credit_amount: 1_000,
+        start_time: now + 100,
+        end_time: now + 1000,
+    };
+    assert_error(
+        env.add_fixed_credit_voucher(&mint, &currency_mint, params)
+            .await,
+        VoucherAlreadyRegistered,
+    );
+    assert!(
+        !env.account_exists(&interest_rate_voucher_address(&mint))
+            .await
+    );
+    assert!(
+        !env.account_exists(&fixed_credit_voucher_address(&mint))
+            .await
+    );
+}
+
+#[tokio::test]
+async fn voucher_already_registered_for_repay() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let params = AddInterestRateVoucherParams {
+        rate_reduction_bps: 100,
+        start_time: now + 100,
+        end_time: now + 1000,
+    };
+    env.add_interest_rate_voucher(&mint, params).await.unwrap();
+
+    assert_error(
+        env.add_repay_voucher(&mint, repay_voucher_params(now))
+            .await,
+        VoucherAlreadyRegistered,
+    );
+}
+
+#[tokio::test]
+async fn invalid_credit_amount() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let currency_mint = env.create_mint(6).await;
+    let params = AddFixedCreditVoucherParams {
+        credit_amount: 0,
+        start_time: now + 100,
+        end_time: now + 1000,
+    };
+    let result = env
+        .add_fixed_credit_voucher(&mint, &currency_mint, params)
+        .await;
+    assert_error(result, InvalidCreditAmount);
+}
+
+#[tokio::test]
+async fn invalid_currency_mint() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let currency_mint = env.create_mint(6).await;
+    let params = AddFixedCreditVoucherParams {
+        credit_amount: 1_000,
+        start_time: now + 100,
+        end_time: now + 1000,
+    };
+    env.add_fixed_credit_voucher(&mint, &currency_mint, params)
+        .await
+        .unwrap();
+    let user = env.new_funded_keypair().await;
+    env.operator_airdrop(&mint, &user.pubkey()).await.unwrap();
+    env.warp_to_timestamp(now + 100).await;
+
+    let other_currency_mint = env.create_mint(6).await;
+    let ix = redeem_fixed_credit_voucher_ix(&user.pubkey(), &mint, &other_currency_mint);
+    assert_error(env.process(&[ix], &[&user]).await, InvalidCurrencyMint);
+}
+
+#[tokio::test]
+async fn fixed_credit_voucher_revoked() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let currency_mint = env.create_mint(6).await;
+    let params = AddFixedCreditVoucherParams {
+        credit_amount: 1_000,
+        start_time: now + 100,
+        end_time: now + 1000,
+    };
+    env.add_fixed_credit_voucher(&mint, &currency_mint, params)
+        .await
+        .unwrap();
+    let user = env.new_funded_keypair().await;
+    env.operator_airdrop(&mint, &user.pubkey()).await.unwrap();
+    env.revoke_voucher(&fixed_credit_voucher_address(&mint), &user.pubkey(), &mint)
+        .await
+        .unwrap();
+
+    let fixed_credit_voucher: FixedCreditVoucher =
+        env.get_account(&fixed_credit_voucher_address(&mint)).await;
+    assert_eq!(fixed_credit_voucher.header.status, VoucherStatus::Revoked);
+    env.warp_to_timestamp(now + 100).await;
+    let ix = redeem_fixed_credit_voucher_ix(&user.pubkey(), &mint, &currency_mint);
+    assert_error(env.process(&[ix], &[&user]).await, VoucherRevoked);
+}
@@ -0,0 +1,695 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::{keccak, system_program, sysvar};
+use common::*;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+use voucher_nft::constants::{MERKLE_LEAF_PREFIX, MERKLE_NODE_PREFIX, TOKEN_METADATA_PROGRAM_ID};
+use voucher_nft::errors::VoucherNftError::*;
+use voucher_nft::{accounts, instruction};
+use voucher_nft::{
+    AddInterestRateVoucherParams, ApplyRepayDiscountParams, Authorator, ClaimDistributor,
+    ClaimTicket, Config, RepayVoucher, SetPauseParams, TicketNonce, Vault,
+};
+
+#[tokio::test]
+async fn initialize_to_operator_airdrop() {
+    let mut env = setup().await;
+    let vault = vault_address(VAULT_SEED);
+
+    let config: Config = env.get_account(&config_address()).await;
+    assert_eq!(config.admin, env.admin.pubkey());
+    assert_eq!(config.collection, env.collection);
+    assert!(env.account_exists(&authorator_address()).await);
+    let _: Authorator = env.get_account(&authorator_address()).await;
+
+    let vault_account: Vault = env.get_account(&vault).await;
+    assert_eq!(vault_account.operator, env.operator.pubkey());
+    assert_eq!(vault_account.seed, VAULT_SEED);
+    assert_eq!(vault_account.voucher_count, 0);
+
+    let mint = env.mint_voucher().await;
+    let vault_token_account =
+        spl_associated_token_account::get_associated_token_address(&vault, &mint);
+    assert_eq!(env.token_balance(&vault_token_account).await, 1);
+    assert!(env.account_exists(&metadata_address(&mint)).await);
+    assert!(env.account_exists(&master_edition_address(&mint)).await);
+    let vault_account: Vault = env.get_account(&vault).await;
+    assert_eq!(vault_account.voucher_count, 1);
+
+    let now = env.now().await;
+    let params = repay_voucher_params(now);
+    env.add_repay_voucher(&mint, params.clone()).await.unwrap();
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert_eq!(
+        repay_voucher.discount_percentage,
+        params.discount_percentage
+    );
+    assert_eq!(repay_voucher.maximum_amount, params.maximum_amount);
+    assert_eq!(repay_voucher.max_uses, params.max_uses);
+    assert_eq!(repay_voucher.uses_remaining, params.max_uses);
+    assert_eq!(repay_voucher.start_time, params.start_time);
+    assert_eq!(repay_voucher.end_time, params.end_time);
+    assert_eq!(repay_voucher.nft_mint, mint);
+    assert_eq!(repay_voucher.authorator, authorator_address());
+    assert_eq!(repay_voucher.vault, vault);
+    assert!(!repay_voucher.is_redeemed());
+
+    let user = env.new_funded_keypair().await;
+    env.operator_airdrop(&mint, &user.pubkey()).await.unwrap();
+    let user_token_account =
+        spl_associated_token_account::get_associated_token_address(&user.pubkey(), &mint);
+    assert_eq!(env.token_balance(&vault_token_account).await, 0);
+    assert_eq!(env.token_balance(&user_token_account).await, 1);
+    let vault_account: Vault = env.get_account(&vault).await;
+    assert_eq!(vault_account.voucher_count, 0);
+
+    // The vault operator needs no role account
+    let operator_role = operator_role_address(&vault, &env.operator.pubkey());
+    assert!(!env.account_exists(&operator_role).await);
+}
+
+#[tokio::test]
+async fn admin_handover_takes_effect_on_accept() {
+    let mut env = setup().await;
+    let new_admin = env.new_funded_keypair().await;
+    let add_vault_ix = |admin: Pubkey, seed: &str| {
+        build_ix(
+            accounts::AddVault {
+                config: config_address(),
+                vault: vault_address(seed),
+                admin,
+                system_program: system_program::ID,
+            },
+            instruction::AddVault {
+                seed: seed.to_string(),
+                operator: Pubkey::new_unique(),
+            },
+        )
+    };
+    let accept_admin_ix = build_ix(
+        accounts::AcceptAdmin {
+            config: config_address(),
+            pending_admin: new_admin.pubkey(),
+        },
+        instruction::AcceptAdmin {},
+    );
+
+    let ix = build_ix(
+        accounts::ProposeAdmin {
+            config: config_address(),
+            admin: env.admin.pubkey(),
+        },
+        instruction::ProposeAdmin {
+            pending_admin: new_admin.pubkey(),
+        },
+    );
+    env.process(&[ix], &[]).await.unwrap();
+    let config: Config = env.get_account(&config_address()).await;
+    assert_eq!(config.admin, env.admin.pubkey());
+    assert_eq!(config.pending_admin, new_admin.pubkey());
+
+    // The proposal alone grants nothing
+    assert_error(
+        env.process(
+            &[add_vault_ix(new_admin.pubkey(), "VAULT_2")],
+            &[&new_admin],
+        )
+        .await,
+        OnlyAdmin,
+    );
+
+    env.process(&[accept_admin_ix.clone()], &[&new_admin])
+        .await
+        .unwrap();
+    let config: Config = env.get_account(&config_address()).await;
+    assert_eq!(config.admin, new_admin.pubkey());
+    assert_eq!(config.pending_admin, Pubkey::default());
+
+    let previous_admin = env.admin.pubkey();
+    assert_error(
+        env.process(&[add_vault_ix(previous_admin, "VAULT_2")], &[])
+            .await,
+        OnlyAdmin,
+    );
+    env.process(
+        &[add_vault_ix(new_admin.pubkey(), "VAULT_2")],
+        &[&new_admin],
+    )
+    .await
+    .unwrap();
+
+    // A handover cannot be accepted twice, the new slot gives the retry a fresh blockhash
+    let now = env.now().await;
+    env.warp_to_timestamp(now).await;
+    assert_error(
+        env.process(&[accept_admin_ix], &[&new_admin]).await,
+        OnlyPendingAdmin,
+    );
+}
+
+#[tokio::test]
+async fn close_vault_once_vouchers_left() {
+    let mut env = setup().await;
+    let vault = vault_address(VAULT_SEED);
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    let vault_account: Vault = env.get_account(&vault).await;
+    assert_eq!(vault_account.voucher_count, 0);
+
+    // Vouchers sent back by hand are not counted and do not keep the vault open
+    env.return_voucher_to_vault(&user, &mint).await;
+    let ix = env.close_vault_ix();
+    env.process(&[ix], &[]).await.unwrap();
+    assert!(!env.account_exists(&vault).await);
+    let vault_token_account = get_associated_token_address(&vault, &mint);
+    assert_eq!(env.token_balance(&vault_token_account).await, 1);
+}
+
+#[tokio::test]
+async fn pause_flags_gate_their_instructions() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    env.add_repay_voucher(&mint, repay_voucher_params(now))
+        .await
+        .unwrap();
+    let user = env.new_funded_keypair().await;
+    let operator = Keypair::from_bytes(&env.operator.to_bytes()).unwrap();
+    let pause = |paused, mint_paused, airdrop_paused, redeem_paused| SetPauseParams {
+        paused,
+        mint_paused,
+        airdrop_paused,
+        redeem_paused,
+    };
+
+    // Pausing airdrops leaves minting open
+    env.set_pause(pause(false, false, true, false))
+        .await
+        .unwrap();
+    assert_error(
+        env.operator_airdrop(&mint, &user.pubkey()).await,
+        ProgramPaused,
+    );
+    env.mint_voucher().await;
+
+    // The new slot gives the retried airdrop a fresh blockhash
+    env.warp_to_timestamp(now + 100).await;
+    env.set_pause(pause(false, false, false, true))
+        .await
+        .unwrap();
+    env.operator_airdrop(&mint, &user.pubkey()).await.unwrap();
+    assert_error(
+        env.redeem_repay_voucher(&user, &mint, 100).await,
+        ProgramPaused,
+    );
+
+    // The global flag overrides the specific ones
+    env.set_pause(pause(true, false, false, false))
+        .await
+        .unwrap();
+    let result = env.mint_voucher_by(VAULT_SEED, &operator).await.map(|_| ());
+    assert_error(result, ProgramPaused);
+    assert_error(
+        env.redeem_repay_voucher(&user, &mint, 101).await,
+        ProgramPaused,
+    );
+    let config: Config = env.get_account(&config_address()).await;
+    assert!(config.paused);
+
+    env.set_pause(pause(false, false, false, false))
+        .await
+        .unwrap();
+    env.redeem_repay_voucher(&user, &mint, 102).await.unwrap();
+}
+
+#[tokio::test]
+async fn redeem_within_validity_window_burns_voucher() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+
+    env.warp_to_timestamp(repay_voucher.start_time).await;
+    env.redeem_repay_voucher(&user, &mint, 200).await.unwrap();
+
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert!(repay_voucher.is_redeemed());
+    assert_eq!(repay_voucher.redeemed_by, user.pubkey());
+    assert_eq!(repay_voucher.amount_used, 20);
+    let user_token_account =
+        spl_associated_token_account::get_associated_token_address(&user.pubkey(), &mint);
+    assert!(!env.account_exists(&user_token_account).await);
+
+    // Without the burned token account a spent voucher cannot reach the redeem checks
+    assert!(env.redeem_repay_voucher(&user, &mint, 201).await.is_err());
+}
+
+#[tokio::test]
+async fn zero_max_uses_registers_single_use() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let mut params = repay_voucher_params(now);
+    params.max_uses = 0;
+    env.add_repay_voucher(&mint, params).await.unwrap();
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert_eq!(repay_voucher.max_uses, 1);
+    assert_eq!(repay_voucher.uses_remaining, 1);
+
+    let user = env.new_funded_keypair().await;
+    env.operator_airdrop(&mint, &user.pubkey()).await.unwrap();
+    env.warp_to_timestamp(repay_voucher.start_time).await;
+    env.redeem_repay_voucher(&user, &mint, 100).await.unwrap();
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert!(repay_voucher.is_redeemed());
+}
+
+#[tokio::test]
+async fn multi_use_voucher_is_burned_on_last_use() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let mut params = repay_voucher_params(now);
+    params.maximum_amount = 1000;
+    params.max_uses = 3;
+    env.add_repay_voucher(&mint, params).await.unwrap();
+    let user = env.new_funded_keypair().await;
+    env.operator_airdrop(&mint, &user.pubkey()).await.unwrap();
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    env.warp_to_timestamp(repay_voucher.start_time).await;
+    let user_token_account = get_associated_token_address(&user.pubkey(), &mint);
+
+    for (use_index, amount) in [100, 101].into_iter().enumerate() {
+        env.redeem_repay_voucher(&user, &mint, amount)
+            .await
+            .unwrap();
+        let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+        assert_eq!(repay_voucher.uses_remaining, 2 - use_index as u16);
+        assert!(!repay_voucher.is_redeemed());
+        assert_eq!(env.token_balance(&user_token_account).await, 1);
+    }
+
+    env.redeem_repay_voucher(&user, &mint, 102).await.unwrap();
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert_eq!(repay_voucher.uses_remaining, 0);
+    assert_eq!(repay_voucher.amount_used, 30);
+    assert!(repay_voucher.is_redeemed());
+    assert!(!env.account_exists(&user_token_account).await);
+}
+
+#[tokio::test]
+async fn multi_use_budget_is_stored_and_ends_uses() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let mut params = repay_voucher_params(now);
+    params.max_uses = 3;
+    env.add_repay_voucher(&mint, params).await.unwrap();
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert_eq!(repay_voucher.amount_remaining, 30);
+
+    let user = env.new_funded_keypair().await;
+    env.operator_airdrop(&mint, &user.pubkey()).await.unwrap();
+    env.warp_to_timestamp(repay_voucher.start_time).await;
+    env.redeem_repay_voucher(&user, &mint, 200).await.unwrap();
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert_eq!(repay_voucher.amount_used, 20);
+    assert_eq!(repay_voucher.amount_remaining, 10);
+    assert_eq!(repay_voucher.uses_remaining, 2);
+
+    // The second use is capped by the budget and spends the voucher with a use left
+    env.redeem_repay_voucher(&user, &mint, 500).await.unwrap();
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert_eq!(repay_voucher.amount_used, 30);
+    assert_eq!(repay_voucher.amount_remaining, 0);
+    assert_eq!(repay_voucher.uses_remaining, 0);
+    assert!(repay_voucher.is_redeemed());
+    let user_token_account = get_associated_token_address(&user.pubkey(), &mint);
+    assert!(!env.account_exists(&user_token_account).await);
+}
+
+#[tokio::test]
+async fn redeem_through_other_vault_is_rejected() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    env.add_vault("VAULT_2", &Pubkey::new_unique())
+        .await
+        .unwrap();
+    env.warp_to_timestamp(repay_voucher.start_time).await;
+
+    let mut accounts = env.redeem_repay_voucher_accounts(&user.pubkey(), &mint);
+    accounts.vault = vault_address("VAULT_2");
+    let ix = build_ix(accounts, instruction::RedeemRepayVoucher { amount: 200 });
+    assert_error(env.process(&[ix], &[&user]).await, InvalidAccountArgument);
+
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert_eq!(repay_voucher.amount_used, 0);
+}
+
+#[tokio::test]
+async fn revoke_then_unrevoke_restores_redemption() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    let repay_voucher = repay_voucher_address(&mint);
+    env.revoke_voucher(&repay_voucher, &user.pubkey(), &mint)
+        .await
+        .unwrap();
+    let repay_voucher_account: RepayVoucher = env.get_account(&repay_voucher).await;
+    assert!(repay_voucher_account.revoked);
+
+    // The holder keeps the voucher while it is revoked
+    let user_token_account = get_associated_token_address(&user.pubkey(), &mint);
+    assert_eq!(env.token_balance(&user_token_account).await, 1);
+    env.warp_to_timestamp(repay_voucher_account.start_time)
+        .await;
+    assert_error(
+        env.redeem_repay_voucher(&user, &mint, 100).await,
+        VoucherRevoked,
+    );
+
+    env.unrevoke_voucher(&repay_voucher, &user.pubkey(), &mint)
+        .await
+        .unwrap();
+    let repay_voucher_account: RepayVoucher = env.get_account(&repay_voucher).await;
+    assert!(!repay_voucher_account.revoked);
+    env.redeem_repay_voucher(&user, &mint, 101).await.unwrap();
+}
+
+#[tokio::test]
+async fn unrevoke_keeps_soulbound_voucher_frozen() {
+    let mut env = setup().await;
+    env.set_vault_soulbound(true).await.unwrap();
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    env.add_repay_voucher(&mint, repay_voucher_params(now))
+        .await
+        .unwrap();
+    let user = env.new_funded_keypair().await;
+    let operator = Keypair::from_bytes(&env.operator.to_bytes()).unwrap();
+    let mut ix = env.operator_airdrop_ix(&mint, &user.pubkey());
+    for meta in ix.accounts.iter_mut() {
+        if meta.pubkey == user.pubkey() {
+            meta.is_signer = true;
+        }
+    }
+    env.process(&[ix], &[&operator, &user]).await.unwrap();
+
+    // Turning soulbound off later does not release vouchers already handed out
+    let repay_voucher = repay_voucher_address(&mint);
+    env.revoke_voucher(&repay_voucher, &user.pubkey(), &mint)
+        .await
+        .unwrap();
+    env.set_vault_soulbound(false).await.unwrap();
+    env.unrevoke_voucher(&repay_voucher, &user.pubkey(), &mint)
+        .await
+        .unwrap();
+
+    let user_token_account = get_associated_token_address(&user.pubkey(), &mint);
+    let account = env
+        .context
+        .banks_client
+        .get_account(user_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_account = spl_token::state::Account::unpack(&account.data).unwrap();
+    assert!(token_account.is_frozen());
+}
+
+fn apply_repay_discount_ix(
+    holder: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+    consume: bool,
+) -> Instruction {
+    build_ix(
+        accounts::ApplyRepayDiscount {
+            config: config_address(),
+            vault: *vault,
+            holder: *holder,
+            mint: *mint,
+            holder_token_account: get_associated_token_address(holder, mint),
+            repay_voucher: repay_voucher_address(mint),
+            metadata_account: metadata_address(mint),
+            master_edition: master_edition_address(mint),
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            token_program: spl_token::ID,
+        },
+        instruction::ApplyRepayDiscount {
+            params: ApplyRepayDiscountParams { amount, consume },
+        },
+    )
+}
+
+#[tokio::test]
+async fn apply_repay_discount_returns_discount() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    let vault = vault_address(VAULT_SEED);
+    env.warp_to_timestamp(repay_voucher.start_time).await;
+
+    // A quote leaves the voucher untouched
+    let ix = apply_repay_discount_ix(&user.pubkey(), &vault, &mint, 200, false);
+    let return_data = env.process_return_data(ix, &[&user]).await.unwrap();
+    assert_eq!(return_data, 20u64.to_le_bytes());
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert_eq!(repay_voucher.amount_used, 0);
+    assert!(!repay_voucher.is_redeemed());
+
+    // The discount is capped by the remaining budget of 30
+    let ix = apply_repay_discount_ix(&user.pubkey(), &vault, &mint, 500, true);
+    let return_data = env.process_return_data(ix, &[&user]).await.unwrap();
+    assert_eq!(return_data, 30u64.to_le_bytes());
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert_eq!(repay_voucher.amount_used, 30);
+    assert!(repay_voucher.is_redeemed());
+    let user_token_account = get_associated_token_address(&user.pubkey(), &mint);
+    assert!(!env.account_exists(&user_token_account).await);
+}
+
+#[tokio::test]
+async fn apply_repay_discount_through_other_vault_is_rejected() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    env.add_vault("VAULT_2", &Pubkey::new_unique())
+        .await
+        .unwrap();
+    env.warp_to_timestamp(repay_voucher.start_time).await;
+
+    let ix = apply_repay_discount_ix(&user.pubkey(), &vault_address("VAULT_2"), &mint, 200, true);
+    assert_error(env.process(&[ix], &[&user]).await, InvalidAccountArgument);
+
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert_eq!(repay_voucher.amount_used, 0);
+}
+
+fn get_interest_rate_reduction_ix(holder: &Pubkey, mint: &Pubkey) -> Instruction {
+    build_ix(
+        accounts::GetInterestRateReduction {
+            config: config_address(),
+            holder: *holder,
+            mint: *mint,
+            holder_token_account: get_associated_token_address(holder, mint),
+            interest_rate_voucher: interest_rate_voucher_address(mint),
+        },
+        instruction::GetInterestRateReduction {},
+    )
+}
+
+#[tokio::test]
+async fn get_interest_rate_reduction_returns_bps_while_active() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    let params = AddInterestRateVoucherParams {
+        rate_reduction_bps: 250,
+        start_time: now + 100,
+        end_time: now + 1000,
+    };
+    env.add_interest_rate_voucher(&mint, params).await.unwrap();
+    let user = Keypair::new();
+    env.operator_airdrop(&mint, &user.pubkey()).await.unwrap();
+    let ix = get_interest_rate_reduction_ix(&user.pubkey(), &mint);
+
+    assert_error(env.process(&[ix.clone()], &[]).await, VoucherNotStarted);
+
+    env.warp_to_timestamp(now + 100).await;
+    let return_data = env.process_return_data(ix.clone(), &[]).await.unwrap();
+    assert_eq!(return_data, 250u16.to_le_bytes());
+
+    // Every inactive state is rejected instead of reading as a 0 bps reduction
+    let interest_rate_voucher = interest_rate_voucher_address(&mint);
+    env.revoke_voucher(&interest_rate_voucher, &user.pubkey(), &mint)
+        .await
+        .unwrap();
+    assert_error(env.process(&[ix.clone()], &[]).await, VoucherRevoked);
+    env.unrevoke_voucher(&interest_rate_voucher, &user.pubkey(), &mint)
+        .await
+        .unwrap();
+
+    env.warp_to_timestamp(now + 1001).await;
+    assert_error(env.process(&[ix], &[]).await, VoucherExpired);
+}
+
+fn claim_leaf(user: &Pubkey, mint: &Pubkey) -> [u8; 32] {
+    keccak::hashv(&[&[MERKLE_LEAF_PREFIX], user.as_ref(), mint.as_ref()]).0
+}
+
+fn claim_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    keccak::hashv(&[&[MERKLE_NODE_PREFIX], left, right]).0
+}
+
+#[tokio::test]
+async fn claim_with_merkle_proof_then_clawback() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let vault = vault_address(VAULT_SEED);
+    let mint = env.mint_voucher().await;
+    let other_mint = env.mint_voucher().await;
+    let user = env.new_funded_keypair().await;
+    let other_leaf = claim_leaf(&Pubkey::new_unique(), &other_mint);
+    let merkle_root = claim_node(&claim_leaf(&user.pubkey(), &mint), &other_leaf);
+    env.create_claim_distributor(merkle_root, now + 1000)
+        .await
+        .unwrap();
+    let distributor = claim_distributor_address(&vault, &merkle_root);
+    let distributor_account: ClaimDistributor = env.get_account(&distributor).await;
+    assert_eq!(distributor_account.payer, env.operator.pubkey());
+
+    let ix = build_ix(
+        accounts::ClaimVoucher {
+            config: config_address(),
+            vault,
+            distributor,
+            user: user.pubkey(),
+            mint,
+            master_edition: master_edition_address(&mint),
+            vault_token_account: get_associated_token_address(&vault, &mint),
+            user_token_account: get_associated_token_address(&user.pubkey(), &mint),
+            claim_status: claim_status_address(&distributor, &mint),
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            associated_token_program: spl_associated_token_account::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::ClaimVoucher {
+            proof: vec![other_leaf],
+        },
+    );
+    env.process(&[ix], &[&user]).await.unwrap();
+    let user_token_account = get_associated_token_address(&user.pubkey(), &mint);
+    assert_eq!(env.token_balance(&user_token_account).await, 1);
+
+    // The rent can only go back to the account that funded the distributor
+    env.warp_to_timestamp(now + 1001).await;
+    let operator = Keypair::from_bytes(&env.operator.to_bytes()).unwrap();
+    let clawback_ix = |payer: Pubkey| {
+        build_ix(
+            accounts::Clawback {
+                vault,
+                operator: operator.pubkey(),
+                operator_role: operator_role_address(&vault, &operator.pubkey()),
+                distributor,
+                payer,
+            },
+            instruction::Clawback {},
+        )
+    };
+    assert_error(
+        env.process(&[clawback_ix(user.pubkey())], &[&operator])
+            .await,
+        InvalidAccountArgument,
+    );
+    env.process(&[clawback_ix(operator.pubkey())], &[&operator])
+        .await
+        .unwrap();
+    assert!(!env.account_exists(&distributor).await);
+
+    // The unclaimed voucher is still in the vault and still counted
+    let vault_account: Vault = env.get_account(&vault).await;
+    assert_eq!(vault_account.voucher_count, 1);
+    let vault_token_account = get_associated_token_address(&vault, &other_mint);
+    assert_eq!(env.token_balance(&vault_token_account).await, 1);
+}
+
+#[tokio::test]
+async fn claim_with_ticket_once_per_nonce() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let vault = vault_address(VAULT_SEED);
+    let mint = env.mint_voucher().await;
+    let other_mint = env.mint_voucher().await;
+    let recipient = env.new_funded_keypair().await;
+    let operator = Keypair::from_bytes(&env.operator.to_bytes()).unwrap();
+    let ticket = |mint: Pubkey| ClaimTicket {
+        vault,
+        mint,
+        recipient: recipient.pubkey(),
+        expiry: now + 1000,
+        nonce: 7,
+    };
+
+    let ixs = [
+        ticket_signature_ix(&operator, &ticket(mint)),
+        claim_with_ticket_ix(&operator.pubkey(), ticket(mint)),
+    ];
+    env.process(&ixs, &[&recipient]).await.unwrap();
+    let recipient_token_account = get_associated_token_address(&recipient.pubkey(), &mint);
+    assert_eq!(env.token_balance(&recipient_token_account).await, 1);
+    let ticket_nonce: TicketNonce = env.get_account(&ticket_nonce_address(&vault, 7)).await;
+    assert_eq!(ticket_nonce.recipient, recipient.pubkey());
+
+    // A second ticket with a used nonce is rejected even for another voucher
+    let ixs = [
+        ticket_signature_ix(&operator, &ticket(other_mint)),
+        claim_with_ticket_ix(&operator.pubkey(), ticket(other_mint)),
+    ];
+    assert!(env.process(&ixs, &[&recipient]).await.is_err());
+    let vault_token_account = get_associated_token_address(&vault, &other_mint);
+    assert_eq!(env.token_balance(&vault_token_account).await, 1);
+    let vault_account: Vault = env.get_account(&vault).await;
+    assert_eq!(vault_account.voucher_count, 1);
+}
+
+#[tokio::test]
+async fn soulbound_airdrop_is_cosigned_by_recipient() {
+    let mut env = setup().await;
+    env.set_vault_soulbound(true).await.unwrap();
+    let mint = env.mint_voucher().await;
+    let user = env.new_funded_keypair().await;
+
+    // Only the owner can approve the vault as delegate of the recipient token account
+    let mut ix = env.operator_airdrop_ix(&mint, &user.pubkey());
+    for meta in ix.accounts.iter_mut() {
+        if meta.pubkey == user.pubkey() {
+            meta.is_signer = true;
+        }
+    }
+    let operator = Keypair::from_bytes(&env.operator.to_bytes()).unwrap();
+    env.process(&[ix], &[&operator, &user]).await.unwrap();
+
+    let user_token_account = get_associated_token_address(&user.pubkey(), &mint);
+    let account = env
+        .context
+        .banks_client
+        .get_account(user_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_account = spl_token::state::Account::unpack(&account.data).unwrap();
+    assert_eq!(token_account.amount, 1);
+    assert!(token_account.is_frozen());
+    assert_eq!(
+        token_account.delegate,
+        COption::Some(vault_address(VAULT_SEED))
+    );
+}
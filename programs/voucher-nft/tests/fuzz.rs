@@ -0,0 +1,394 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::{system_program, sysvar};
+use common::*;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+use std::collections::HashMap;
+use voucher_nft::constants::TOKEN_METADATA_PROGRAM_ID;
+use voucher_nft::{accounts, instruction};
+use voucher_nft::{AddRepayVoucherParams, RepayVoucher, Vault};
+
+const SECOND_VAULT_SEED: &str = "VAULT_2";
+// Fixed so CI runs are reproducible, explore other sequences with FUZZ_SEED
+const DEFAULT_FUZZ_SEED: u64 = 0x5eed_cafe_f00d_1234;
+
+// Small xorshift generator, the seed is printed so a failing run can be replayed
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + self.below((high - low + 1) as u64) as i64
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len() as u64) as usize]
+    }
+}
+
+fn env_u64(name: &str) -> Option<u64> {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+}
+
+struct Fuzzer {
+    env: TestEnv,
+    rng: Rng,
+    operators: Vec<Keypair>,
+    // Vault seeds with the index of their operator in `operators`
+    vaults: Vec<(&'static str, usize)>,
+    // Ground truth: vouchers minted through the program and the vault that minted them
+    minted_by: HashMap<Pubkey, Pubkey>,
+    // The same vouchers in minting order, so a seed always replays the same picks
+    vault_mints: Vec<Pubkey>,
+    mints: Vec<Pubkey>,
+    holders: Vec<Pubkey>,
+}
+
+impl Fuzzer {
+    async fn new(seed: u64) -> Self {
+        let mut env = setup().await;
+        let operator = Keypair::from_bytes(&env.operator.to_bytes()).unwrap();
+        let second_operator = env.new_funded_keypair().await;
+        let attacker = env.new_funded_keypair().await;
+        env.add_vault(SECOND_VAULT_SEED, &second_operator.pubkey())
+            .await
+            .unwrap();
+
+        let mut minted_by = HashMap::new();
+        let mut vault_mints = vec![];
+        for (seed, signer) in [
+            (VAULT_SEED, &operator),
+            (VAULT_SEED, &operator),
+            (SECOND_VAULT_SEED, &second_operator),
+        ] {
+            let mint = env.mint_voucher_by(seed, signer).await.unwrap();
+            minted_by.insert(mint, vault_address(seed));
+            vault_mints.push(mint);
+        }
+
+        // Look alike candidates: a foreign NFT, a plain mint and the collection
+        let mut mints = vault_mints.clone();
+        mints.push(env.create_foreign_nft().await);
+        mints.push(env.create_mint(0).await);
+        mints.push(env.collection);
+
+        let holders = vec![
+            vault_address(VAULT_SEED),
+            vault_address(SECOND_VAULT_SEED),
+            attacker.pubkey(),
+            env.context.payer.pubkey(),
+        ];
+        Self {
+            env,
+            rng: Rng(seed.max(1)),
+            operators: vec![operator, second_operator, attacker],
+            vaults: vec![(VAULT_SEED, 0), (SECOND_VAULT_SEED, 1)],
+            minted_by,
+            vault_mints,
+            mints,
+            holders,
+        }
+    }
+
+    fn random_operator(&mut self) -> usize {
+        self.rng.below(self.operators.len() as u64) as usize
+    }
+
+    fn random_vault(&mut self) -> Pubkey {
+        let (seed, _) = self.rng.pick(&self.vaults);
+        vault_address(seed)
+    }
+
+    fn random_mint(&mut self) -> Pubkey {
+        self.rng.pick(&self.mints)
+    }
+
+    fn vault_operator(&self, vault: &Pubkey) -> usize {
+        self.vaults
+            .iter()
+            .find(|(seed, _)| vault_address(seed) == *vault)
+            .map(|(_, operator)| *operator)
+            .unwrap()
+    }
+
+    // Half the inputs stay valid, the others get exactly one of `fields` replaced
+    fn mutated_field(&mut self, fields: u64) -> Option<u64> {
+        let field = self.rng.below(fields * 2);
+        if field < fields {
+            Some(field)
+        } else {
+            None
+        }
+    }
+
+    // An account derived for another mint or a random key, in place of the expected one
+    fn look_alike(&mut self, derive: fn(&Pubkey) -> Pubkey) -> Pubkey {
+        match self.rng.below(2) {
+            0 => {
+                let other = self.random_mint();
+                derive(&other)
+            }
+            _ => Pubkey::new_unique(),
+        }
+    }
+
+    fn random_operator_role(&mut self, operator: &Pubkey) -> Pubkey {
+        match self.rng.below(2) {
+            0 => {
+                let other_vault = self.random_vault();
+                operator_role_address(&other_vault, operator)
+            }
+            _ => Pubkey::new_unique(),
+        }
+    }
+
+    fn valid_repay_params(&mut self, now: i64) -> AddRepayVoucherParams {
+        let start_time = self.rng.range(now + 10, now + 200);
+        AddRepayVoucherParams {
+            discount_percentage: self.rng.below(10_001) as u16,
+            maximum_amount: 1 + self.rng.below(100) as u32,
+            max_uses: self.rng.below(4) as u16,
+            start_time,
+            end_time: self.rng.range(start_time + 1, start_time + 500),
+        }
+    }
+
+    async fn add_repay_voucher(&mut self) -> (Instruction, usize) {
+        let now = self.env.now().await;
+        let mint = self.rng.pick(&self.vault_mints);
+        let vault = self.minted_by[&mint];
+        let mut signer = self.vault_operator(&vault);
+        let operator = self.operators[signer].pubkey();
+        let mut accounts = accounts::AddRepayVoucher {
+            config: config_address(),
+            vault,
+            operator,
+            operator_role: operator_role_address(&vault, &operator),
+            mint,
+            metadata_account: metadata_address(&mint),
+            master_edition: master_edition_address(&mint),
+            repay_voucher: repay_voucher_address(&mint),
+            interest_rate_voucher: interest_rate_voucher_address(&mint),
+            fixed_credit_voucher: fixed_credit_voucher_address(&mint),
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            system_program: system_program::ID,
+        };
+        let mut params = self.valid_repay_params(now);
+
+        match self.mutated_field(13) {
+            Some(0) => {
+                signer = self.random_operator();
+                accounts.operator = self.operators[signer].pubkey();
+            }
+            Some(1) => accounts.vault = self.random_vault(),
+            Some(2) => accounts.mint = self.random_mint(),
+            Some(3) => accounts.operator_role = self.random_operator_role(&operator),
+            Some(4) => accounts.metadata_account = self.look_alike(metadata_address),
+            Some(5) => accounts.master_edition = self.look_alike(master_edition_address),
+            Some(6) => accounts.repay_voucher = self.look_alike(repay_voucher_address),
+            Some(7) => {
+                accounts.interest_rate_voucher = self.look_alike(interest_rate_voucher_address)
+            }
+            Some(8) => {
+                accounts.fixed_credit_voucher = self.look_alike(fixed_credit_voucher_address)
+            }
+            Some(9) => params.discount_percentage = 10_001 + self.rng.below(2_000) as u16,
+            Some(10) => params.maximum_amount = 0,
+            Some(11) => params.start_time = self.rng.range(now - 50, now - 1),
+            Some(12) => params.end_time = self.rng.range(params.start_time - 10, params.start_time),
+            _ => {}
+        }
+        let ix = build_ix(accounts, instruction::AddVoucherRepayInformation { params });
+        (ix, signer)
+    }
+
+    async fn operator_airdrop(&mut self) -> (Instruction, usize) {
+        // Valid inputs need a voucher still held by its vault, once all are handed out
+        // the airdrop can only fail
+        let mut in_vault = vec![];
+        for mint in self.vault_mints.clone() {
+            let vault = self.minted_by[&mint];
+            if self.token_amount(&vault, &mint).await == 1 {
+                in_vault.push(mint);
+            }
+        }
+        let mint = if in_vault.is_empty() {
+            self.rng.pick(&self.vault_mints)
+        } else {
+            self.rng.pick(&in_vault)
+        };
+        let vault = self.minted_by[&mint];
+        let mut signer = self.vault_operator(&vault);
+        let operator = self.operators[signer].pubkey();
+        let user = Pubkey::new_unique();
+        self.holders.push(user);
+        let mut accounts = accounts::OperatorAirdrop {
+            config: config_address(),
+            vault,
+            operator,
+            operator_role: operator_role_address(&vault, &operator),
+            user,
+            mint,
+            master_edition: master_edition_address(&mint),
+            vault_token_account: get_associated_token_address(&vault, &mint),
+            user_token_account: get_associated_token_address(&user, &mint),
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            associated_token_program: spl_associated_token_account::ID,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        };
+
+        match self.mutated_field(8) {
+            Some(0) => {
+                signer = self.random_operator();
+                accounts.operator = self.operators[signer].pubkey();
+            }
+            Some(1) => accounts.vault = self.random_vault(),
+            Some(2) => accounts.mint = self.random_mint(),
+            Some(3) => accounts.operator_role = self.random_operator_role(&operator),
+            Some(4) => accounts.user = self.rng.pick(&self.holders),
+            Some(5) => accounts.master_edition = self.look_alike(master_edition_address),
+            Some(6) => {
+                let owner = self.rng.pick(&self.holders);
+                accounts.vault_token_account = get_associated_token_address(&owner, &mint);
+            }
+            Some(7) => {
+                let owner = self.rng.pick(&self.holders);
+                accounts.user_token_account = get_associated_token_address(&owner, &mint);
+            }
+            _ => {}
+        }
+        (build_ix(accounts, instruction::OperatorAirdrop {}), signer)
+    }
+
+    async fn token_amount(&mut self, owner: &Pubkey, mint: &Pubkey) -> u64 {
+        let address = get_associated_token_address(owner, mint);
+        match self
+            .env
+            .context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+        {
+            Some(account) => spl_token::state::Account::unpack(&account.data)
+                .map(|token_account| token_account.amount)
+                .unwrap_or(0),
+            None => 0,
+        }
+    }
+
+    async fn check_invariants(&mut self, step: usize) {
+        let authorator = authorator_address();
+        for mint in self.mints.clone() {
+            // Only vault-created, authorator-verified NFTs ever get a RepayVoucher
+            let repay_voucher_address = repay_voucher_address(&mint);
+            if self.env.account_exists(&repay_voucher_address).await {
+                let repay_voucher: RepayVoucher =
+                    self.env.get_account(&repay_voucher_address).await;
+                let minted_by = self.minted_by.get(&mint);
+                assert_eq!(
+                    minted_by,
+                    Some(&repay_voucher.vault),
+                    "step {}: repay voucher registered for {} outside its vault",
+                    step,
+                    mint
+                );
+                assert_eq!(repay_voucher.nft_mint, mint, "step {}", step);
+                assert_eq!(repay_voucher.authorator, authorator, "step {}", step);
+            }
+
+            // Transfers only move the single voucher token, never create or lose one
+            let mut held = 0;
+            for holder in self.holders.clone() {
+                held += self.token_amount(&holder, &mint).await;
+            }
+            let mint_account = self
+                .env
+                .context
+                .banks_client
+                .get_account(mint)
+                .await
+                .unwrap()
+                .unwrap();
+            let supply = spl_token::state::Mint::unpack(&mint_account.data)
+                .unwrap()
+                .supply;
+            assert!(
+                held <= supply,
+                "step {}: {} tokens of {} held but supply is {}",
+                step,
+                held,
+                mint,
+                supply
+            );
+        }
+
+        // Vault counters follow what the vault token accounts actually hold
+        for (seed, _) in self.vaults.clone() {
+            let vault = vault_address(seed);
+            let mut in_vault = 0;
+            for (mint, minted_by) in self.minted_by.clone() {
+                if minted_by == vault {
+                    in_vault += self.token_amount(&vault, &mint).await;
+                }
+            }
+            let vault_account: Vault = self.env.get_account(&vault).await;
+            assert_eq!(
+                vault_account.voucher_count, in_vault,
+                "step {}: vault {} counter out of sync",
+                step, seed
+            );
+        }
+    }
+}
+
+// Valid add_voucher_repay_information and operator_airdrop inputs with one account or
+// parameter mutated half of the time. Tune with FUZZ_ITERATIONS and pick another
+// sequence with FUZZ_SEED
+#[tokio::test]
+async fn fuzz_account_permutations() {
+    let seed = env_u64("FUZZ_SEED").unwrap_or(DEFAULT_FUZZ_SEED);
+    let iterations = env_u64("FUZZ_ITERATIONS").unwrap_or(64) as usize;
+    println!("fuzzing {} iterations with FUZZ_SEED={}", iterations, seed);
+
+    let mut fuzzer = Fuzzer::new(seed).await;
+    fuzzer.check_invariants(0).await;
+    let names = ["add_voucher_repay_information", "operator_airdrop"];
+    let mut succeeded = [0usize; 2];
+    for step in 1..=iterations {
+        let target = fuzzer.rng.below(2) as usize;
+        let (ix, signer) = match target {
+            0 => fuzzer.add_repay_voucher().await,
+            _ => fuzzer.operator_airdrop().await,
+        };
+        let signer = Keypair::from_bytes(&fuzzer.operators[signer].to_bytes()).unwrap();
+        if fuzzer.env.process(&[ix], &[&signer]).await.is_ok() {
+            succeeded[target] += 1;
+        }
+        fuzzer.check_invariants(step).await;
+    }
+
+    // The invariants only mean something if some transactions changed the state
+    for (name, count) in names.iter().zip(succeeded) {
+        println!("{} succeeded {} times", name, count);
+        assert!(count > 0, "no {} transaction succeeded", name);
+    }
+}
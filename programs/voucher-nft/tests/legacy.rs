@@ -0,0 +1,150 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use solana_sdk::signature::Signer;
+use voucher_nft::errors::VoucherNftError::*;
+use voucher_nft::{accounts, instruction, RepayVoucher, UpdateRepayVoucherParams, Vault};
+
+// Accounts created before a field was carved out of the reserve read it as zero
+async fn legacy_repay_voucher(env: &mut TestEnv, mint: &Pubkey) -> RepayVoucher {
+    let address = repay_voucher_address(mint);
+    let mut repay_voucher: RepayVoucher = env.get_account(&address).await;
+    repay_voucher.vault = Pubkey::default();
+    repay_voucher.max_uses = 0;
+    repay_voucher.uses_remaining = 0;
+    repay_voucher.amount_remaining = 0;
+    env.set_account_data(&address, &repay_voucher).await;
+    repay_voucher
+}
+
+#[tokio::test]
+async fn close_untracked_vault() {
+    let mut env = setup().await;
+    let vault = vault_address(VAULT_SEED);
+    let mut vault_account: Vault = env.get_account(&vault).await;
+    vault_account.voucher_count_tracked = false;
+    env.set_account_data(&vault, &vault_account).await;
+
+    let ix = env.close_vault_ix();
+    assert_error(env.process(&[ix], &[]).await, VaultCountNotTracked);
+}
+
+#[tokio::test]
+async fn update_legacy_repay_voucher_records_vault() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    env.add_repay_voucher(&mint, repay_voucher_params(now))
+        .await
+        .unwrap();
+    let repay_voucher = legacy_repay_voucher(&mut env, &mint).await;
+
+    let params = UpdateRepayVoucherParams {
+        discount_percentage: repay_voucher.discount_percentage + 1,
+        maximum_amount: repay_voucher.maximum_amount,
+        start_time: repay_voucher.start_time,
+        end_time: repay_voucher.end_time,
+    };
+    env.update_repay_voucher(&mint, params.clone())
+        .await
+        .unwrap();
+
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert_eq!(repay_voucher.vault, vault_address(VAULT_SEED));
+    assert_eq!(
+        repay_voucher.discount_percentage,
+        params.discount_percentage
+    );
+}
+
+#[tokio::test]
+async fn legacy_repay_voucher_rejects_other_vault() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    env.add_repay_voucher(&mint, repay_voucher_params(now))
+        .await
+        .unwrap();
+    let repay_voucher = legacy_repay_voucher(&mut env, &mint).await;
+
+    // The NFT metadata lists the first vault as creator, not this one
+    let operator = env.new_funded_keypair().await;
+    env.add_vault("VAULT_2", &operator.pubkey()).await.unwrap();
+    let vault = vault_address("VAULT_2");
+    let ix = build_ix(
+        accounts::UpdateRepayVoucher {
+            vault,
+            operator: operator.pubkey(),
+            operator_role: operator_role_address(&vault, &operator.pubkey()),
+            metadata_account: metadata_address(&mint),
+            repay_voucher: repay_voucher_address(&mint),
+        },
+        instruction::UpdateRepayVoucher {
+            params: UpdateRepayVoucherParams {
+                discount_percentage: repay_voucher.discount_percentage,
+                maximum_amount: repay_voucher.maximum_amount,
+                start_time: repay_voucher.start_time,
+                end_time: repay_voucher.end_time,
+            },
+        },
+    );
+    assert_error(env.process(&[ix], &[&operator]).await, VaultNotSigned);
+
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert_eq!(repay_voucher.vault, Pubkey::default());
+}
+
+#[tokio::test]
+async fn redeem_legacy_repay_voucher_records_vault() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    let repay_voucher = legacy_repay_voucher(&mut env, &mint).await;
+
+    env.warp_to_timestamp(repay_voucher.start_time).await;
+    env.redeem_repay_voucher(&user, &mint, 100).await.unwrap();
+
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert!(repay_voucher.is_redeemed());
+    assert_eq!(repay_voucher.vault, vault_address(VAULT_SEED));
+    // The budget falls back to the maximum amount and the use stores what is left
+    assert_eq!(repay_voucher.amount_used, 10);
+    assert_eq!(repay_voucher.amount_remaining, 20);
+}
+
+#[tokio::test]
+async fn revoke_legacy_repay_voucher() {
+    let mut env = setup().await;
+    let (mint, user) = env.airdropped_repay_voucher().await;
+    legacy_repay_voucher(&mut env, &mint).await;
+
+    env.revoke_voucher(&repay_voucher_address(&mint), &user.pubkey(), &mint)
+        .await
+        .unwrap();
+
+    let repay_voucher: RepayVoucher = env.get_account(&repay_voucher_address(&mint)).await;
+    assert!(repay_voucher.revoked);
+    assert_eq!(repay_voucher.vault, vault_address(VAULT_SEED));
+}
+
+#[tokio::test]
+async fn close_expired_legacy_repay_voucher() {
+    let mut env = setup().await;
+    let now = env.now().await;
+    let mint = env.mint_voucher().await;
+    env.add_repay_voucher(&mint, repay_voucher_params(now))
+        .await
+        .unwrap();
+    let repay_voucher = legacy_repay_voucher(&mut env, &mint).await;
+
+    env.warp_to_timestamp(repay_voucher.end_time + 1).await;
+    env.close_repay_voucher(&mint, true).await.unwrap();
+
+    assert!(!env.account_exists(&repay_voucher_address(&mint)).await);
+    let vault = vault_address(VAULT_SEED);
+    let vault_token_account =
+        spl_associated_token_account::get_associated_token_address(&vault, &mint);
+    assert!(!env.account_exists(&vault_token_account).await);
+    let vault_account: Vault = env.get_account(&vault).await;
+    assert_eq!(vault_account.voucher_count, 0);
+}
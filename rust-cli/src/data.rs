@@ -0,0 +1,99 @@
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, Context, Result};
+use chrono::NaiveDateTime;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::fs;
+use std::str::FromStr;
+use voucher_nft::{AddRepayVoucherParams, MetadataParams, UpdateRepayVoucherParams};
+
+// Same shape as `MetadataInformation` in cli/types.ts, e.g. cli/data/collection.json
+#[derive(Debug, Deserialize)]
+pub struct MetadataInformation {
+    pub name: String,
+    pub symbol: String,
+    #[serde(default)]
+    pub description: String,
+    pub uri: String,
+}
+
+impl MetadataInformation {
+    pub fn to_params(&self) -> MetadataParams {
+        MetadataParams {
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            uri: self.uri.clone(),
+        }
+    }
+}
+
+// Same shape as `RepayVoucherInformation` in cli/types.ts, e.g. cli/data/repay_voucher.json
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepayVoucherInformation {
+    #[serde(flatten)]
+    pub metadata: MetadataInformation,
+    pub start_time: String,
+    pub end_time: String,
+    // Base 10000, passed to the program unchanged
+    pub discount_percentage: u16,
+    #[serde(alias = "maximumAmount")]
+    pub max_discount: u32,
+    #[serde(default = "default_max_uses")]
+    pub max_uses: u16,
+}
+
+fn default_max_uses() -> u16 {
+    1
+}
+
+impl RepayVoucherInformation {
+    pub fn to_add_params(&self) -> Result<AddRepayVoucherParams> {
+        Ok(AddRepayVoucherParams {
+            discount_percentage: self.discount_percentage,
+            maximum_amount: self.max_discount,
+            max_uses: self.max_uses,
+            start_time: parse_date_time(&self.start_time)?,
+            end_time: parse_date_time(&self.end_time)?,
+        })
+    }
+
+    pub fn to_update_params(&self) -> Result<UpdateRepayVoucherParams> {
+        Ok(UpdateRepayVoucherParams {
+            discount_percentage: self.discount_percentage,
+            maximum_amount: self.max_discount,
+            start_time: parse_date_time(&self.start_time)?,
+            end_time: parse_date_time(&self.end_time)?,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AirdropInformation {
+    pub mint: String,
+    pub user: String,
+}
+
+pub fn read_json<T: DeserializeOwned>(path: &str) -> Result<T> {
+    let content = fs::read_to_string(path).with_context(|| format!("failed to read {}", path))?;
+    serde_json::from_str(&content).with_context(|| format!("failed to parse {}", path))
+}
+
+pub fn parse_pubkey(value: &str) -> Result<Pubkey> {
+    Pubkey::from_str(value).map_err(|_| anyhow!("invalid address {}", value))
+}
+
+// Format DD/mm/YYYY hh:mm in UTC, e.g. 01/01/2022 00:00, same as the TypeScript cli
+pub fn parse_date_time(value: &str) -> Result<i64> {
+    let date_time = NaiveDateTime::parse_from_str(value, "%d/%m/%Y %H:%M")
+        .with_context(|| format!("invalid date {}, expected DD/mm/YYYY hh:mm", value))?;
+    Ok(date_time.timestamp())
+}
+
+pub fn parse_hash(value: &str) -> Result<[u8; 32]> {
+    let bytes = hex::decode(value.trim_start_matches("0x"))
+        .with_context(|| format!("invalid hex {}", value))?;
+    bytes
+        .try_into()
+        .map_err(|_| anyhow!("expected 32 bytes hash, got {}", value))
+}
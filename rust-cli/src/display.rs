@@ -0,0 +1,55 @@
+use anchor_lang::prelude::Pubkey;
+use voucher_nft::{Config, FixedCreditVoucher, InterestRateVoucher, RepayVoucher, Vault};
+
+pub fn print_config(address: &Pubkey, config: &Config) {
+    println!("Config {}", address);
+    println!("  admin:          {}", config.admin);
+    println!("  pending admin:  {}", config.pending_admin);
+    println!("  collection:     {}", config.collection);
+    println!("  paused:         {}", config.paused);
+    println!("  mint paused:    {}", config.mint_paused);
+    println!("  airdrop paused: {}", config.airdrop_paused);
+    println!("  redeem paused:  {}", config.redeem_paused);
+}
+
+pub fn print_vault(address: &Pubkey, vault: &Vault) {
+    println!("Vault {}", address);
+    println!("  seed:          {}", vault.seed);
+    println!("  operator:      {}", vault.operator);
+    println!("  voucher count: {}", vault.voucher_count);
+    println!("  soulbound:     {}", vault.soulbound);
+}
+
+pub fn print_repay_voucher(address: &Pubkey, voucher: &RepayVoucher) {
+    println!("Repay voucher {}", address);
+    println!("  mint:                {}", voucher.nft_mint);
+    println!("  vault:               {}", voucher.vault);
+    println!("  authorator:          {}", voucher.authorator);
+    println!("  discount percentage: {}", voucher.discount_percentage);
+    println!("  maximum amount:      {}", voucher.maximum_amount);
+    println!("  amount used:         {}", voucher.amount_used);
+    println!("  remaining amount:    {}", voucher.remaining_amount());
+    println!("  max uses:            {}", voucher.max_uses);
+    println!("  uses remaining:      {}", voucher.uses_remaining);
+    println!("  start time:          {}", voucher.start_time);
+    println!("  end time:            {}", voucher.end_time);
+    println!("  status:              {:?}", voucher.header().status);
+    if voucher.redeemed_at != 0 {
+        println!("  redeemed by:         {}", voucher.redeemed_by);
+        println!("  redeemed at:         {}", voucher.redeemed_at);
+    }
+}
+
+pub fn print_interest_rate_voucher(address: &Pubkey, voucher: &InterestRateVoucher) {
+    println!("Interest rate voucher {}", address);
+    println!("  header:             {:?}", voucher.header);
+    println!("  rate reduction bps: {}", voucher.rate_reduction_bps);
+}
+
+pub fn print_fixed_credit_voucher(address: &Pubkey, voucher: &FixedCreditVoucher) {
+    println!("Fixed credit voucher {}", address);
+    println!("  header:           {:?}", voucher.header);
+    println!("  currency mint:    {}", voucher.currency_mint);
+    println!("  credit amount:    {}", voucher.credit_amount);
+    println!("  remaining credit: {}", voucher.remaining_credit);
+}
@@ -0,0 +1,1003 @@
+mod data;
+mod display;
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, Result};
+use clap::{ArgEnum, Parser, Subcommand};
+use data::*;
+use display::*;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::compute_budget;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use voucher_nft::{
+    AddFixedCreditVoucherParams, AddInterestRateVoucherParams, ApplyRepayDiscountParams,
+    ClaimTicket, SetPauseParams, VoucherKind,
+};
+use voucher_nft_client::{accounts, instructions, Pda};
+
+// Minting creates metadata and master edition accounts, which exceeds the default compute limit
+const MINT_COMPUTE_UNITS: u32 = 1_000_000;
+
+#[derive(ArgEnum, Clone, Copy, Debug)]
+enum Network {
+    Mainnet,
+    Testnet,
+    Localnet,
+}
+
+impl Network {
+    fn url(self) -> &'static str {
+        match self {
+            Network::Mainnet => "https://api-mainnet-beta.renec.foundation:8899/",
+            Network::Testnet => "https://api-testnet.renec.foundation:8899/",
+            Network::Localnet => "http://127.0.0.1:8899",
+        }
+    }
+}
+
+#[derive(Parser)]
+#[clap(name = "voucher-nft", about = "Manage the voucher-nft program")]
+struct Cli {
+    /// Network type
+    #[clap(long, arg_enum, global = true, default_value = "localnet")]
+    network: Network,
+
+    /// Keypair path of the signer and fee payer
+    #[clap(long, global = true, default_value = "~/.config/renec/id.json")]
+    source: String,
+
+    /// ProgramId if needed
+    #[clap(long = "program_id", global = true)]
+    program_id: Option<String>,
+
+    /// Print the signed transaction in base64 instead of sending it, the blockhash is still
+    /// fetched from the network
+    #[clap(long = "dry_run", global = true)]
+    dry_run: bool,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Initialize the program and create the collection NFT
+    Initialize {
+        /// Json path of the collection details
+        #[clap(long = "metadata_path")]
+        metadata_path: String,
+    },
+    /// Propose a new admin
+    ProposeAdmin {
+        #[clap(long = "pending_admin")]
+        pending_admin: String,
+    },
+    /// Accept the admin role, signed by the pending admin
+    AcceptAdmin,
+    /// Pause or unpause the program
+    SetPause {
+        #[clap(long)]
+        paused: bool,
+        #[clap(long = "mint_paused")]
+        mint_paused: bool,
+        #[clap(long = "airdrop_paused")]
+        airdrop_paused: bool,
+        #[clap(long = "redeem_paused")]
+        redeem_paused: bool,
+    },
+    /// Add a vault to the program
+    AddVault {
+        #[clap(long)]
+        seed: String,
+        #[clap(long = "operator_address")]
+        operator_address: String,
+    },
+    /// Change the operator of a vault
+    SetVaultOperator {
+        #[clap(long)]
+        seed: String,
+        #[clap(long = "operator_address")]
+        operator_address: String,
+    },
+    /// Toggle soulbound mode of a vault, its airdrops then need the recipient signature
+    SetVaultSoulbound {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        soulbound: bool,
+    },
+    /// Close an empty vault
+    CloseVault {
+        #[clap(long)]
+        seed: String,
+    },
+    /// Grant permissions of a vault to an operator
+    GrantOperatorRole {
+        #[clap(long)]
+        seed: String,
+        #[clap(long = "operator_address")]
+        operator_address: String,
+        /// Bit mask: 1 minter, 2 information manager, 4 airdropper
+        #[clap(long)]
+        permissions: u8,
+    },
+    /// Revoke permissions of a vault from an operator
+    RevokeOperatorRole {
+        #[clap(long)]
+        seed: String,
+        #[clap(long = "operator_address")]
+        operator_address: String,
+        #[clap(long)]
+        permissions: u8,
+    },
+    /// Mint a voucher NFT into the vault
+    MintVoucher {
+        #[clap(long)]
+        seed: String,
+        /// Json path of the voucher metadata
+        #[clap(long = "metadata_path")]
+        metadata_path: String,
+    },
+    /// Mint a voucher NFT directly to a recipient
+    MintVoucherTo {
+        #[clap(long)]
+        seed: String,
+        #[clap(long = "metadata_path")]
+        metadata_path: String,
+        #[clap(long)]
+        recipient: String,
+    },
+    /// Mint repay voucher
+    MintRepayVoucher {
+        #[clap(long)]
+        seed: String,
+        /// Json path of the repay voucher
+        #[clap(long = "metadata_path")]
+        metadata_path: String,
+    },
+    /// Register repay information of a minted voucher
+    AddRepayVoucher {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        mint: String,
+        #[clap(long = "metadata_path")]
+        metadata_path: String,
+    },
+    /// Register interest rate information of a minted voucher
+    AddInterestRateVoucher {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        mint: String,
+        #[clap(long = "rate_reduction_bps")]
+        rate_reduction_bps: u16,
+        /// DD/mm/YYYY hh:mm in UTC
+        #[clap(long = "start_time")]
+        start_time: String,
+        #[clap(long = "end_time")]
+        end_time: String,
+    },
+    /// Register fixed credit information of a minted voucher
+    AddFixedCreditVoucher {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        mint: String,
+        #[clap(long = "currency_mint")]
+        currency_mint: String,
+        #[clap(long = "credit_amount")]
+        credit_amount: u64,
+        #[clap(long = "start_time")]
+        start_time: String,
+        #[clap(long = "end_time")]
+        end_time: String,
+    },
+    /// Update repay information before the voucher is active
+    UpdateRepayVoucher {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        mint: String,
+        #[clap(long = "metadata_path")]
+        metadata_path: String,
+    },
+    /// Close a redeemed or expired repay voucher
+    CloseRepayVoucher {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        mint: String,
+        /// Also burn the voucher if it is still held by the vault
+        #[clap(long)]
+        burn: bool,
+    },
+    /// Airdrop voucher to the user
+    AirdropVoucher {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        mint: String,
+        #[clap(long)]
+        user: String,
+        /// Keypair of the user, required to co-sign airdrops from a soulbound vault
+        #[clap(long = "user_keypair")]
+        user_keypair: Option<String>,
+    },
+    /// Airdrop vouchers listed in a json file of [{ "mint", "user" }]
+    BatchAirdropVoucher {
+        #[clap(long)]
+        seed: String,
+        #[clap(long = "airdrops_path")]
+        airdrops_path: String,
+    },
+    /// Simulate a query of the current interest rate reduction of a voucher
+    GetInterestRateReduction {
+        #[clap(long)]
+        mint: String,
+        #[clap(long)]
+        holder: String,
+    },
+    /// Revoke a voucher of any kind
+    RevokeVoucher {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        mint: String,
+        #[clap(long = "holder_token_account")]
+        holder_token_account: String,
+    },
+    /// Restore a revoked voucher
+    UnrevokeVoucher {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        mint: String,
+        #[clap(long = "holder_token_account")]
+        holder_token_account: String,
+    },
+    /// Create a merkle claim distributor
+    CreateClaimDistributor {
+        #[clap(long)]
+        seed: String,
+        /// Hex encoded merkle root
+        #[clap(long = "merkle_root")]
+        merkle_root: String,
+        #[clap(long = "end_time")]
+        end_time: String,
+    },
+    /// Claim a voucher from a distributor, signed by the user
+    ClaimVoucher {
+        #[clap(long)]
+        seed: String,
+        #[clap(long = "merkle_root")]
+        merkle_root: String,
+        #[clap(long)]
+        mint: String,
+        /// Hex encoded proof nodes
+        #[clap(long, multiple_occurrences = true)]
+        proof: Vec<String>,
+    },
+    /// Claim a voucher with a ticket signed by an airdropper, signed by the recipient
+    ClaimWithTicket {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        mint: String,
+        /// Keypair path of the ticket signer
+        #[clap(long = "ticket_signer")]
+        ticket_signer: String,
+        #[clap(long)]
+        expiry: i64,
+        #[clap(long)]
+        nonce: u64,
+    },
+    /// Close a finished claim distributor
+    Clawback {
+        #[clap(long)]
+        seed: String,
+        #[clap(long = "merkle_root")]
+        merkle_root: String,
+    },
+    /// Redeem a repay voucher, signed by the holder
+    RedeemRepayVoucher {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        mint: String,
+        #[clap(long)]
+        amount: u64,
+    },
+    /// Redeem a fixed credit voucher, signed by the holder
+    RedeemFixedCreditVoucher {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        mint: String,
+        #[clap(long = "currency_mint")]
+        currency_mint: String,
+        #[clap(long)]
+        amount: u64,
+    },
+    /// Apply a repay voucher to an amount, signed by the holder. Without --consume the
+    /// discount is simulated and printed
+    ApplyRepayDiscount {
+        #[clap(long)]
+        seed: String,
+        #[clap(long)]
+        mint: String,
+        #[clap(long)]
+        amount: u64,
+        #[clap(long)]
+        consume: bool,
+    },
+    /// Show the program config
+    ShowConfig,
+    /// List all vaults of the program
+    ListVaults,
+    /// Show the voucher information of a mint
+    ShowVoucher { mint: String },
+}
+
+struct Context {
+    client: RpcClient,
+    signer: Keypair,
+    pda: Pda,
+    dry_run: bool,
+}
+
+impl Context {
+    fn send(&self, instructions: Vec<Instruction>, extra_signers: &[&Keypair]) -> Result<()> {
+        let mut signers: Vec<&Keypair> = vec![&self.signer];
+        signers.extend_from_slice(extra_signers);
+
+        let blockhash = self.client.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&self.signer.pubkey()),
+            &signers,
+            blockhash,
+        );
+
+        if self.dry_run {
+            println!("{}", base64::encode(bincode::serialize(&transaction)?));
+            return Ok(());
+        }
+        let signature = self.client.send_and_confirm_transaction(&transaction)?;
+        println!("Transaction success at {}", signature);
+        Ok(())
+    }
+
+    // Read-only queries are simulated and never sent. solana-client 1.9 has no return data
+    // in the simulation result, so it is read from the "Program return: <id> <base64>" log
+    fn simulate(&self, instruction: Instruction) -> Result<Vec<u8>> {
+        let blockhash = self.client.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.signer.pubkey()),
+            &[&self.signer],
+            blockhash,
+        );
+
+        let result = self.client.simulate_transaction(&transaction)?.value;
+        let logs = result.logs.unwrap_or_default();
+        if let Some(err) = result.err {
+            for log in &logs {
+                println!("{}", log);
+            }
+            return Err(anyhow!("simulation failed: {}", err));
+        }
+
+        let prefix = format!("Program return: {} ", self.pda.program_id);
+        let encoded = logs
+            .iter()
+            .find_map(|log| log.strip_prefix(&prefix))
+            .ok_or_else(|| anyhow!("the program returned no data"))?;
+        Ok(base64::decode(encoded)?)
+    }
+
+    fn vault(&self, seed: &str) -> Pubkey {
+        self.pda.vault(seed).0
+    }
+
+    fn collection(&self) -> Result<Pubkey> {
+        Ok(accounts::fetch_config(&self.client, &self.pda)?.collection)
+    }
+}
+
+// Trailing zero bytes may be dropped from return data, pad it back to the value width
+fn return_value<const N: usize>(data: &[u8]) -> Result<[u8; N]> {
+    if data.len() > N {
+        return Err(anyhow!("unexpected return data of {} bytes", data.len()));
+    }
+    let mut value = [0u8; N];
+    value[..data.len()].copy_from_slice(data);
+    Ok(value)
+}
+
+// Same layout as `ComputeBudgetProgram.setComputeUnitLimit` in the TypeScript sdk
+fn set_compute_unit_limit_ix(units: u32) -> Instruction {
+    let mut data = vec![2u8];
+    data.extend_from_slice(&units.to_le_bytes());
+    Instruction::new_with_bytes(compute_budget::id(), &data, vec![])
+}
+
+fn read_keypair(path: &str) -> Result<Keypair> {
+    let path = match path.strip_prefix("~/") {
+        Some(rest) => format!("{}/{}", std::env::var("HOME")?, rest),
+        None => path.to_string(),
+    };
+    read_keypair_file(&path).map_err(|err| anyhow!("failed to read keypair {}: {}", path, err))
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let program_id = match &cli.program_id {
+        Some(program_id) => parse_pubkey(program_id)?,
+        None => voucher_nft::ID,
+    };
+    let ctx = Context {
+        client: RpcClient::new_with_commitment(
+            cli.network.url().to_string(),
+            CommitmentConfig::confirmed(),
+        ),
+        signer: read_keypair(&cli.source)?,
+        pda: Pda::new(program_id),
+        dry_run: cli.dry_run,
+    };
+    let signer = ctx.signer.pubkey();
+    let pda = &ctx.pda;
+
+    match cli.command {
+        Command::Initialize { metadata_path } => {
+            let metadata: MetadataInformation = read_json(&metadata_path)?;
+            let collection = Keypair::new();
+            println!("Collection mint {}", collection.pubkey());
+            ctx.send(
+                vec![
+                    set_compute_unit_limit_ix(MINT_COMPUTE_UNITS),
+                    instructions::initialize(
+                        pda,
+                        &signer,
+                        &collection.pubkey(),
+                        metadata.to_params(),
+                    ),
+                ],
+                &[&collection],
+            )
+        }
+        Command::ProposeAdmin { pending_admin } => ctx.send(
+            vec![instructions::propose_admin(
+                pda,
+                &signer,
+                &parse_pubkey(&pending_admin)?,
+            )],
+            &[],
+        ),
+        Command::AcceptAdmin => ctx.send(vec![instructions::accept_admin(pda, &signer)], &[]),
+        Command::SetPause {
+            paused,
+            mint_paused,
+            airdrop_paused,
+            redeem_paused,
+        } => ctx.send(
+            vec![instructions::set_pause(
+                pda,
+                &signer,
+                SetPauseParams {
+                    paused,
+                    mint_paused,
+                    airdrop_paused,
+                    redeem_paused,
+                },
+            )],
+            &[],
+        ),
+        Command::AddVault {
+            seed,
+            operator_address,
+        } => {
+            println!("Vault {}", ctx.vault(&seed));
+            ctx.send(
+                vec![instructions::add_vault(
+                    pda,
+                    &signer,
+                    &seed,
+                    &parse_pubkey(&operator_address)?,
+                )],
+                &[],
+            )
+        }
+        Command::SetVaultOperator {
+            seed,
+            operator_address,
+        } => ctx.send(
+            vec![instructions::set_vault_operator(
+                pda,
+                &signer,
+                &ctx.vault(&seed),
+                &parse_pubkey(&operator_address)?,
+            )],
+            &[],
+        ),
+        Command::SetVaultSoulbound { seed, soulbound } => ctx.send(
+            vec![instructions::set_vault_soulbound(
+                pda,
+                &signer,
+                &ctx.vault(&seed),
+                soulbound,
+            )],
+            &[],
+        ),
+        Command::CloseVault { seed } => ctx.send(
+            vec![instructions::close_vault(pda, &signer, &ctx.vault(&seed))],
+            &[],
+        ),
+        Command::GrantOperatorRole {
+            seed,
+            operator_address,
+            permissions,
+        } => ctx.send(
+            vec![instructions::grant_operator_role(
+                pda,
+                &signer,
+                &ctx.vault(&seed),
+                &parse_pubkey(&operator_address)?,
+                permissions,
+            )],
+            &[],
+        ),
+        Command::RevokeOperatorRole {
+            seed,
+            operator_address,
+            permissions,
+        } => ctx.send(
+            vec![instructions::revoke_operator_role(
+                pda,
+                &signer,
+                &ctx.vault(&seed),
+                &parse_pubkey(&operator_address)?,
+                permissions,
+            )],
+            &[],
+        ),
+        Command::MintVoucher {
+            seed,
+            metadata_path,
+        } => {
+            let metadata: MetadataInformation = read_json(&metadata_path)?;
+            let mint = Keypair::new();
+            println!("Voucher mint {}", mint.pubkey());
+            ctx.send(
+                vec![
+                    set_compute_unit_limit_ix(MINT_COMPUTE_UNITS),
+                    instructions::mint_voucher(
+                        pda,
+                        &signer,
+                        &ctx.vault(&seed),
+                        &mint.pubkey(),
+                        &ctx.collection()?,
+                        metadata.to_params(),
+                    ),
+                ],
+                &[&mint],
+            )
+        }
+        Command::MintVoucherTo {
+            seed,
+            metadata_path,
+            recipient,
+        } => {
+            let metadata: MetadataInformation = read_json(&metadata_path)?;
+            let mint = Keypair::new();
+            println!("Voucher mint {}", mint.pubkey());
+            ctx.send(
+                vec![
+                    set_compute_unit_limit_ix(MINT_COMPUTE_UNITS),
+                    instructions::mint_voucher_to(
+                        pda,
+                        &signer,
+                        &ctx.vault(&seed),
+                        &mint.pubkey(),
+                        &parse_pubkey(&recipient)?,
+                        &ctx.collection()?,
+                        metadata.to_params(),
+                    ),
+                ],
+                &[&mint],
+            )
+        }
+        Command::MintRepayVoucher {
+            seed,
+            metadata_path,
+        } => {
+            let information: RepayVoucherInformation = read_json(&metadata_path)?;
+            let mint = Keypair::new();
+            println!("Repay voucher mint {}", mint.pubkey());
+            ctx.send(
+                vec![
+                    set_compute_unit_limit_ix(MINT_COMPUTE_UNITS),
+                    instructions::mint_repay_voucher(
+                        pda,
+                        &signer,
+                        &ctx.vault(&seed),
+                        &mint.pubkey(),
+                        &ctx.collection()?,
+                        information.metadata.to_params(),
+                        information.to_add_params()?,
+                    ),
+                ],
+                &[&mint],
+            )
+        }
+        Command::AddRepayVoucher {
+            seed,
+            mint,
+            metadata_path,
+        } => {
+            let information: RepayVoucherInformation = read_json(&metadata_path)?;
+            ctx.send(
+                vec![instructions::add_voucher_repay_information(
+                    pda,
+                    &signer,
+                    &ctx.vault(&seed),
+                    &parse_pubkey(&mint)?,
+                    information.to_add_params()?,
+                )],
+                &[],
+            )
+        }
+        Command::AddInterestRateVoucher {
+            seed,
+            mint,
+            rate_reduction_bps,
+            start_time,
+            end_time,
+        } => ctx.send(
+            vec![instructions::add_voucher_interest_information(
+                pda,
+                &signer,
+                &ctx.vault(&seed),
+                &parse_pubkey(&mint)?,
+                AddInterestRateVoucherParams {
+                    rate_reduction_bps,
+                    start_time: parse_date_time(&start_time)?,
+                    end_time: parse_date_time(&end_time)?,
+                },
+            )],
+            &[],
+        ),
+        Command::AddFixedCreditVoucher {
+            seed,
+            mint,
+            currency_mint,
+            credit_amount,
+            start_time,
+            end_time,
+        } => ctx.send(
+            vec![instructions::add_voucher_fixed_credit_information(
+                pda,
+                &signer,
+                &ctx.vault(&seed),
+                &parse_pubkey(&mint)?,
+                &parse_pubkey(&currency_mint)?,
+                AddFixedCreditVoucherParams {
+                    credit_amount,
+                    start_time: parse_date_time(&start_time)?,
+                    end_time: parse_date_time(&end_time)?,
+                },
+            )],
+            &[],
+        ),
+        Command::UpdateRepayVoucher {
+            seed,
+            mint,
+            metadata_path,
+        } => {
+            let information: RepayVoucherInformation = read_json(&metadata_path)?;
+            ctx.send(
+                vec![instructions::update_repay_voucher(
+                    pda,
+                    &signer,
+                    &ctx.vault(&seed),
+                    &parse_pubkey(&mint)?,
+                    information.to_update_params()?,
+                )],
+                &[],
+            )
+        }
+        Command::CloseRepayVoucher { seed, mint, burn } => ctx.send(
+            vec![instructions::close_repay_voucher(
+                pda,
+                &signer,
+                &ctx.vault(&seed),
+                &parse_pubkey(&mint)?,
+                burn,
+            )],
+            &[],
+        ),
+        Command::AirdropVoucher {
+            seed,
+            mint,
+            user,
+            user_keypair,
+        } => {
+            let user = parse_pubkey(&user)?;
+            let ix = instructions::operator_airdrop(
+                pda,
+                &signer,
+                &ctx.vault(&seed),
+                &parse_pubkey(&mint)?,
+                &user,
+            );
+            match user_keypair {
+                Some(path) => {
+                    let user_keypair = read_keypair(&path)?;
+                    if user_keypair.pubkey() != user {
+                        return Err(anyhow!("user keypair does not match {}", user));
+                    }
+                    ctx.send(vec![instructions::with_signer(ix, &user)], &[&user_keypair])
+                }
+                None => ctx.send(vec![ix], &[]),
+            }
+        }
+        Command::BatchAirdropVoucher {
+            seed,
+            airdrops_path,
+        } => {
+            let airdrops: Vec<AirdropInformation> = read_json(&airdrops_path)?;
+            let airdrops = airdrops
+                .iter()
+                .map(|airdrop| Ok((parse_pubkey(&airdrop.mint)?, parse_pubkey(&airdrop.user)?)))
+                .collect::<Result<Vec<_>>>()?;
+            ctx.send(
+                vec![
+                    set_compute_unit_limit_ix(MINT_COMPUTE_UNITS),
+                    instructions::batch_operator_airdrop(
+                        pda,
+                        &signer,
+                        &ctx.vault(&seed),
+                        &airdrops,
+                    ),
+                ],
+                &[],
+            )
+        }
+        Command::GetInterestRateReduction { mint, holder } => {
+            let data = ctx.simulate(instructions::get_interest_rate_reduction(
+                pda,
+                &parse_pubkey(&holder)?,
+                &parse_pubkey(&mint)?,
+            ))?;
+            let rate_reduction_bps = u16::from_le_bytes(return_value(&data)?);
+            println!("Rate reduction {} bps", rate_reduction_bps);
+            Ok(())
+        }
+        Command::RevokeVoucher {
+            seed,
+            mint,
+            holder_token_account,
+        } => {
+            let mint = parse_pubkey(&mint)?;
+            ctx.send(
+                vec![instructions::revoke_voucher(
+                    pda,
+                    &signer,
+                    &ctx.vault(&seed),
+                    voucher_kind(&ctx, &mint)?,
+                    &mint,
+                    &parse_pubkey(&holder_token_account)?,
+                )],
+                &[],
+            )
+        }
+        Command::UnrevokeVoucher {
+            seed,
+            mint,
+            holder_token_account,
+        } => {
+            let mint = parse_pubkey(&mint)?;
+            ctx.send(
+                vec![instructions::unrevoke_voucher(
+                    pda,
+                    &signer,
+                    &ctx.vault(&seed),
+                    voucher_kind(&ctx, &mint)?,
+                    &mint,
+                    &parse_pubkey(&holder_token_account)?,
+                )],
+                &[],
+            )
+        }
+        Command::CreateClaimDistributor {
+            seed,
+            merkle_root,
+            end_time,
+        } => ctx.send(
+            vec![instructions::create_claim_distributor(
+                pda,
+                &signer,
+                &ctx.vault(&seed),
+                parse_hash(&merkle_root)?,
+                parse_date_time(&end_time)?,
+            )],
+            &[],
+        ),
+        Command::ClaimVoucher {
+            seed,
+            merkle_root,
+            mint,
+            proof,
+        } => {
+            let proof = proof
+                .iter()
+                .map(|node| parse_hash(node))
+                .collect::<Result<Vec<_>>>()?;
+            ctx.send(
+                vec![instructions::claim_voucher(
+                    pda,
+                    &signer,
+                    &ctx.vault(&seed),
+                    &parse_hash(&merkle_root)?,
+                    &parse_pubkey(&mint)?,
+                    proof,
+                )],
+                &[],
+            )
+        }
+        Command::ClaimWithTicket {
+            seed,
+            mint,
+            ticket_signer,
+            expiry,
+            nonce,
+        } => {
+            let ticket_signer = read_keypair(&ticket_signer)?;
+            let ticket = ClaimTicket {
+                vault: ctx.vault(&seed),
+                mint: parse_pubkey(&mint)?,
+                recipient: signer,
+                expiry,
+                nonce,
+            };
+            ctx.send(
+                vec![
+                    instructions::claim_ticket_signature(pda, &ticket_signer, &ticket),
+                    instructions::claim_with_ticket(pda, &ticket_signer.pubkey(), ticket),
+                ],
+                &[],
+            )
+        }
+        Command::Clawback { seed, merkle_root } => {
+            let vault = ctx.vault(&seed);
+            let merkle_root = parse_hash(&merkle_root)?;
+            let distributor =
+                accounts::fetch_claim_distributor(&ctx.client, pda, &vault, &merkle_root)?;
+            ctx.send(
+                vec![instructions::clawback(
+                    pda,
+                    &signer,
+                    &vault,
+                    &merkle_root,
+                    &distributor.payer,
+                )],
+                &[],
+            )
+        }
+        Command::RedeemRepayVoucher { seed, mint, amount } => ctx.send(
+            vec![instructions::redeem_repay_voucher(
+                pda,
+                &signer,
+                &ctx.vault(&seed),
+                &parse_pubkey(&mint)?,
+                amount,
+            )],
+            &[],
+        ),
+        Command::RedeemFixedCreditVoucher {
+            seed,
+            mint,
+            currency_mint,
+            amount,
+        } => ctx.send(
+            vec![instructions::redeem_fixed_credit_voucher(
+                pda,
+                &signer,
+                &ctx.vault(&seed),
+                &parse_pubkey(&mint)?,
+                &parse_pubkey(&currency_mint)?,
+                amount,
+            )],
+            &[],
+        ),
+        Command::ApplyRepayDiscount {
+            seed,
+            mint,
+            amount,
+            consume,
+        } => {
+            let ix = instructions::apply_repay_discount(
+                pda,
+                &signer,
+                &ctx.vault(&seed),
+                &parse_pubkey(&mint)?,
+                ApplyRepayDiscountParams { amount, consume },
+            );
+            if consume {
+                return ctx.send(vec![ix], &[]);
+            }
+
+            // Without consume the discount is only quoted
+            let discount = u64::from_le_bytes(return_value(&ctx.simulate(ix)?)?);
+            println!("Discount {}", discount);
+            Ok(())
+        }
+        Command::ShowConfig => {
+            let config = accounts::fetch_config(&ctx.client, pda)?;
+            print_config(&pda.config().0, &config);
+            Ok(())
+        }
+        Command::ListVaults => {
+            for (address, vault) in accounts::fetch_vaults(&ctx.client, pda)? {
+                print_vault(&address, &vault);
+            }
+            Ok(())
+        }
+        Command::ShowVoucher { mint } => show_voucher(&ctx, &parse_pubkey(&mint)?),
+    }
+}
+
+// Missing accounts are expected while probing voucher kinds, other errors are not
+fn optional<T>(result: voucher_nft_client::Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(account) => Ok(Some(account)),
+        Err(voucher_nft_client::Error::AccountNotFound(_)) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+// The program registers a single voucher kind per mint, found from whichever information account exists
+fn voucher_kind(ctx: &Context, mint: &Pubkey) -> Result<VoucherKind> {
+    let pda = &ctx.pda;
+    if optional(accounts::fetch_repay_voucher(&ctx.client, pda, mint))?.is_some() {
+        return Ok(VoucherKind::Repay);
+    }
+    if optional(accounts::fetch_interest_rate_voucher(
+        &ctx.client,
+        pda,
+        mint,
+    ))?
+    .is_some()
+    {
+        return Ok(VoucherKind::InterestRate);
+    }
+    if optional(accounts::fetch_fixed_credit_voucher(&ctx.client, pda, mint))?.is_some() {
+        return Ok(VoucherKind::FixedCredit);
+    }
+    Err(anyhow!("no voucher information found for mint {}", mint))
+}
+
+// Print whichever information account exists
+fn show_voucher(ctx: &Context, mint: &Pubkey) -> Result<()> {
+    let pda = &ctx.pda;
+    if let Some(voucher) = optional(accounts::fetch_repay_voucher(&ctx.client, pda, mint))? {
+        print_repay_voucher(&pda.repay_voucher(mint).0, &voucher);
+        return Ok(());
+    }
+    if let Some(voucher) = optional(accounts::fetch_interest_rate_voucher(
+        &ctx.client,
+        pda,
+        mint,
+    ))? {
+        print_interest_rate_voucher(&pda.interest_rate_voucher(mint).0, &voucher);
+        return Ok(());
+    }
+    if let Some(voucher) = optional(accounts::fetch_fixed_credit_voucher(&ctx.client, pda, mint))? {
+        print_fixed_credit_voucher(&pda.fixed_credit_voucher(mint).0, &voucher);
+        return Ok(());
+    }
+    Err(anyhow!("no voucher information found for mint {}", mint))
+}
@@ -75,6 +75,66 @@ export type VoucherNft = {
                 },
             ];
         },
+        {
+            name: 'proposeAdmin';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'admin';
+                    isMut: false;
+                    isSigner: true;
+                },
+            ];
+            args: [
+                {
+                    name: 'pendingAdmin';
+                    type: 'publicKey';
+                },
+            ];
+        },
+        {
+            name: 'acceptAdmin';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'pendingAdmin';
+                    isMut: false;
+                    isSigner: true;
+                },
+            ];
+            args: [];
+        },
+        {
+            name: 'setPause';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'admin';
+                    isMut: false;
+                    isSigner: true;
+                },
+            ];
+            args: [
+                {
+                    name: 'params';
+                    type: {
+                        defined: 'SetPauseParams';
+                    };
+                },
+            ];
+        },
         {
             name: 'addVault';
             accounts: [
@@ -111,7 +171,7 @@ export type VoucherNft = {
             ];
         },
         {
-            name: 'mintVoucher';
+            name: 'setVaultOperator';
             accounts: [
                 {
                     name: 'config';
@@ -120,119 +180,200 @@ export type VoucherNft = {
                 },
                 {
                     name: 'vault';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'admin';
+                    isMut: false;
+                    isSigner: true;
+                },
+            ];
+            args: [
+                {
+                    name: 'operator';
+                    type: 'publicKey';
+                },
+            ];
+        },
+        {
+            name: 'setVaultSoulbound';
+            accounts: [
+                {
+                    name: 'config';
                     isMut: false;
                     isSigner: false;
                 },
                 {
-                    name: 'authorator';
+                    name: 'vault';
                     isMut: true;
                     isSigner: false;
                 },
                 {
-                    name: 'operator';
-                    isMut: true;
+                    name: 'admin';
+                    isMut: false;
                     isSigner: true;
                 },
+            ];
+            args: [
                 {
-                    name: 'mint';
-                    isMut: true;
-                    isSigner: true;
+                    name: 'soulbound';
+                    type: 'bool';
                 },
+            ];
+        },
+        {
+            name: 'closeVault';
+            accounts: [
                 {
-                    name: 'vaultTokenAccount';
-                    isMut: true;
+                    name: 'config';
+                    isMut: false;
                     isSigner: false;
                 },
                 {
-                    name: 'metadataAccount';
+                    name: 'vault';
                     isMut: true;
                     isSigner: false;
                 },
                 {
-                    name: 'masterEdition';
+                    name: 'admin';
                     isMut: true;
+                    isSigner: true;
+                },
+            ];
+            args: [];
+        },
+        {
+            name: 'grantOperatorRole';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
                     isSigner: false;
                 },
                 {
-                    name: 'collectionMint';
-                    isMut: true;
+                    name: 'vault';
+                    isMut: false;
                     isSigner: false;
                 },
                 {
-                    name: 'collectionMetadataAccount';
+                    name: 'operatorRole';
                     isMut: true;
                     isSigner: false;
                 },
                 {
-                    name: 'collectionMasterEdition';
+                    name: 'admin';
                     isMut: true;
-                    isSigner: false;
+                    isSigner: true;
                 },
                 {
-                    name: 'tokenMetadataProgram';
+                    name: 'systemProgram';
                     isMut: false;
                     isSigner: false;
                 },
+            ];
+            args: [
+                {
+                    name: 'operator';
+                    type: 'publicKey';
+                },
                 {
-                    name: 'systemProgram';
+                    name: 'permissions';
+                    type: 'u8';
+                },
+            ];
+        },
+        {
+            name: 'revokeOperatorRole';
+            accounts: [
+                {
+                    name: 'config';
                     isMut: false;
                     isSigner: false;
                 },
                 {
-                    name: 'tokenProgram';
+                    name: 'vault';
                     isMut: false;
                     isSigner: false;
                 },
                 {
-                    name: 'associatedTokenProgram';
-                    isMut: false;
+                    name: 'operatorRole';
+                    isMut: true;
                     isSigner: false;
                 },
                 {
-                    name: 'rent';
+                    name: 'admin';
                     isMut: false;
-                    isSigner: false;
+                    isSigner: true;
                 },
             ];
             args: [
                 {
-                    name: 'params';
-                    type: {
-                        defined: 'MetadataParams';
-                    };
+                    name: 'permissions';
+                    type: 'u8';
                 },
             ];
         },
         {
-            name: 'addVoucherRepayInformation';
+            name: 'mintVoucher';
             accounts: [
                 {
-                    name: 'vault';
+                    name: 'config';
                     isMut: false;
                     isSigner: false;
                 },
+                {
+                    name: 'vault';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'authorator';
+                    isMut: true;
+                    isSigner: false;
+                },
                 {
                     name: 'operator';
                     isMut: true;
                     isSigner: true;
                 },
                 {
-                    name: 'mint';
+                    name: 'operatorRole';
                     isMut: false;
                     isSigner: false;
                 },
+                {
+                    name: 'mint';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'vaultTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
                 {
                     name: 'metadataAccount';
-                    isMut: false;
+                    isMut: true;
                     isSigner: false;
                 },
                 {
                     name: 'masterEdition';
-                    isMut: false;
+                    isMut: true;
                     isSigner: false;
                 },
                 {
-                    name: 'repayVoucher';
+                    name: 'collectionMint';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'collectionMetadataAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'collectionMasterEdition';
                     isMut: true;
                     isSigner: false;
                 },
@@ -246,56 +387,106 @@ export type VoucherNft = {
                     isMut: false;
                     isSigner: false;
                 },
+                {
+                    name: 'tokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'associatedTokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'rent';
+                    isMut: false;
+                    isSigner: false;
+                },
             ];
             args: [
                 {
                     name: 'params';
                     type: {
-                        defined: 'AddRepayVoucherParams';
+                        defined: 'MetadataParams';
                     };
                 },
             ];
         },
         {
-            name: 'operatorAirdrop';
+            name: 'mintVoucherTo';
             accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
                 {
                     name: 'vault';
                     isMut: false;
                     isSigner: false;
                 },
+                {
+                    name: 'authorator';
+                    isMut: true;
+                    isSigner: false;
+                },
                 {
                     name: 'operator';
                     isMut: true;
                     isSigner: true;
                 },
                 {
-                    name: 'user';
+                    name: 'operatorRole';
                     isMut: false;
                     isSigner: false;
                 },
                 {
                     name: 'mint';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'recipient';
                     isMut: false;
                     isSigner: false;
                 },
+                {
+                    name: 'recipientTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'metadataAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
                 {
                     name: 'masterEdition';
-                    isMut: false;
+                    isMut: true;
                     isSigner: false;
                 },
                 {
-                    name: 'vaultTokenAccount';
+                    name: 'collectionMint';
                     isMut: true;
                     isSigner: false;
                 },
                 {
-                    name: 'userTokenAccount';
+                    name: 'collectionMetadataAccount';
                     isMut: true;
                     isSigner: false;
                 },
                 {
-                    name: 'associatedTokenProgram';
+                    name: 'collectionMasterEdition';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenMetadataProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'systemProgram';
                     isMut: false;
                     isSigner: false;
                 },
@@ -305,7 +496,7 @@ export type VoucherNft = {
                     isSigner: false;
                 },
                 {
-                    name: 'systemProgram';
+                    name: 'associatedTokenProgram';
                     isMut: false;
                     isSigner: false;
                 },
@@ -315,703 +506,5884 @@ export type VoucherNft = {
                     isSigner: false;
                 },
             ];
-            args: [];
-        },
-    ];
-    accounts: [
-        {
-            name: 'authorator';
-            type: {
+            args: [
+                {
+                    name: 'params';
+                    type: {
+                        defined: 'MetadataParams';
+                    };
+                },
+            ];
+        },
+        {
+            name: 'mintRepayVoucher';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vault';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'authorator';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'operator';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'mint';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'vaultTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'metadataAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'masterEdition';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'collectionMint';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'collectionMetadataAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'collectionMasterEdition';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'repayVoucher';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenMetadataProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'systemProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'associatedTokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'rent';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [
+                {
+                    name: 'metadataParams';
+                    type: {
+                        defined: 'MetadataParams';
+                    };
+                },
+                {
+                    name: 'repayVoucherParams';
+                    type: {
+                        defined: 'AddRepayVoucherParams';
+                    };
+                },
+            ];
+        },
+        {
+            name: 'addVoucherRepayInformation';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vault';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'operator';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'mint';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'metadataAccount';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'masterEdition';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'repayVoucher';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'interestRateVoucher';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'fixedCreditVoucher';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenMetadataProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'systemProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [
+                {
+                    name: 'params';
+                    type: {
+                        defined: 'AddRepayVoucherParams';
+                    };
+                },
+            ];
+        },
+        {
+            name: 'addVoucherInterestInformation';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vault';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'operator';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'mint';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'metadataAccount';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'masterEdition';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'interestRateVoucher';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'repayVoucher';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'fixedCreditVoucher';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenMetadataProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'systemProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [
+                {
+                    name: 'params';
+                    type: {
+                        defined: 'AddInterestRateVoucherParams';
+                    };
+                },
+            ];
+        },
+        {
+            name: 'addVoucherFixedCreditInformation';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vault';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'operator';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'mint';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'metadataAccount';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'masterEdition';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'currencyMint';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'fixedCreditVoucher';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'repayVoucher';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'interestRateVoucher';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenMetadataProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'systemProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [
+                {
+                    name: 'params';
+                    type: {
+                        defined: 'AddFixedCreditVoucherParams';
+                    };
+                },
+            ];
+        },
+        {
+            name: 'updateRepayVoucher';
+            accounts: [
+                {
+                    name: 'vault';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'operator';
+                    isMut: false;
+                    isSigner: true;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'metadataAccount';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'repayVoucher';
+                    isMut: true;
+                    isSigner: false;
+                },
+            ];
+            args: [
+                {
+                    name: 'params';
+                    type: {
+                        defined: 'UpdateRepayVoucherParams';
+                    };
+                },
+            ];
+        },
+        {
+            name: 'closeRepayVoucher';
+            accounts: [
+                {
+                    name: 'vault';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'operator';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'mint';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'vaultTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'metadataAccount';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'repayVoucher';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [
+                {
+                    name: 'burnVaultVoucher';
+                    type: 'bool';
+                },
+            ];
+        },
+        {
+            name: 'operatorAirdrop';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vault';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'operator';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'user';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'mint';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'masterEdition';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vaultTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'userTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenMetadataProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'associatedTokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'systemProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'rent';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [];
+        },
+        {
+            name: 'batchOperatorAirdrop';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vault';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'operator';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenMetadataProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'associatedTokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'systemProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'rent';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [];
+        },
+        {
+            name: 'getInterestRateReduction';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'holder';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'mint';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'holderTokenAccount';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'interestRateVoucher';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [];
+        },
+        {
+            name: 'revokeVoucher';
+            accounts: [
+                {
+                    name: 'vault';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'operator';
+                    isMut: false;
+                    isSigner: true;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'mint';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'holderTokenAccount';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'metadataAccount';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'voucherInformation';
+                    isMut: true;
+                    isSigner: false;
+                },
+            ];
+            args: [];
+        },
+        {
+            name: 'unrevokeVoucher';
+            accounts: [
+                {
+                    name: 'vault';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'operator';
+                    isMut: false;
+                    isSigner: true;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'mint';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'holderTokenAccount';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'metadataAccount';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'voucherInformation';
+                    isMut: true;
+                    isSigner: false;
+                },
+            ];
+            args: [];
+        },
+        {
+            name: 'createClaimDistributor';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vault';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'operator';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'distributor';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'systemProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [
+                {
+                    name: 'merkleRoot';
+                    type: {
+                        array: ['u8', 32];
+                    };
+                },
+                {
+                    name: 'endTime';
+                    type: 'i64';
+                },
+            ];
+        },
+        {
+            name: 'claimVoucher';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vault';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'distributor';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'user';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'mint';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'masterEdition';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vaultTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'userTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'claimStatus';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenMetadataProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'associatedTokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'systemProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'rent';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [
+                {
+                    name: 'proof';
+                    type: {
+                        vec: {
+                            array: ['u8', 32];
+                        };
+                    };
+                },
+            ];
+        },
+        {
+            name: 'claimWithTicket';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vault';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'ticketSigner';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'recipient';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'mint';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'masterEdition';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vaultTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'recipientTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'ticketNonce';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'instructionsSysvar';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenMetadataProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'associatedTokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'systemProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'rent';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [
+                {
+                    name: 'ticket';
+                    type: {
+                        defined: 'ClaimTicket';
+                    };
+                },
+            ];
+        },
+        {
+            name: 'clawback';
+            accounts: [
+                {
+                    name: 'vault';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'operator';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'operatorRole';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'distributor';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'payer';
+                    isMut: true;
+                    isSigner: false;
+                },
+            ];
+            args: [];
+        },
+        {
+            name: 'redeemRepayVoucher';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vault';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'holder';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'mint';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'holderTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'repayVoucher';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'metadataAccount';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'masterEdition';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenMetadataProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [
+                {
+                    name: 'amount';
+                    type: 'u64';
+                },
+            ];
+        },
+        {
+            name: 'redeemFixedCreditVoucher';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vault';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'holder';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'mint';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'holderTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'fixedCreditVoucher';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'currencyMint';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'masterEdition';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenMetadataProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [
+                {
+                    name: 'amount';
+                    type: 'u64';
+                },
+            ];
+        },
+        {
+            name: 'applyRepayDiscount';
+            accounts: [
+                {
+                    name: 'config';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'vault';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'holder';
+                    isMut: true;
+                    isSigner: true;
+                },
+                {
+                    name: 'mint';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'holderTokenAccount';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'repayVoucher';
+                    isMut: true;
+                    isSigner: false;
+                },
+                {
+                    name: 'metadataAccount';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'masterEdition';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenMetadataProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+                {
+                    name: 'tokenProgram';
+                    isMut: false;
+                    isSigner: false;
+                },
+            ];
+            args: [
+                {
+                    name: 'params';
+                    type: {
+                        defined: 'ApplyRepayDiscountParams';
+                    };
+                },
+            ];
+        },
+    ];
+    accounts: [
+        {
+            name: 'authorator';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'bump';
+                        type: 'u8';
+                    },
+                ];
+            };
+        },
+        {
+            name: 'claimDistributor';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'vault';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'merkleRoot';
+                        type: {
+                            array: ['u8', 32];
+                        };
+                    },
+                    {
+                        name: 'endTime';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'claimedCount';
+                        type: 'u64';
+                    },
+                    {
+                        name: 'bump';
+                        type: 'u8';
+                    },
+                    {
+                        name: 'payer';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'reserve';
+                        type: {
+                            array: ['u128', 2];
+                        };
+                    },
+                ];
+            };
+        },
+        {
+            name: 'claimStatus';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'distributor';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'user';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'mint';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'claimedAt';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'reserve';
+                        type: {
+                            array: ['u128', 2];
+                        };
+                    },
+                ];
+            };
+        },
+        {
+            name: 'config';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'admin';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'collection';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'pendingAdmin';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'paused';
+                        type: 'bool';
+                    },
+                    {
+                        name: 'mintPaused';
+                        type: 'bool';
+                    },
+                    {
+                        name: 'airdropPaused';
+                        type: 'bool';
+                    },
+                    {
+                        name: 'redeemPaused';
+                        type: 'bool';
+                    },
+                    {
+                        name: 'padding';
+                        type: {
+                            array: ['u8', 12];
+                        };
+                    },
+                    {
+                        name: 'reserve';
+                        type: {
+                            array: ['u128', 3];
+                        };
+                    },
+                ];
+            };
+        },
+        {
+            name: 'operatorRole';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'vault';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'operator';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'permissions';
+                        type: 'u8';
+                    },
+                    {
+                        name: 'bump';
+                        type: 'u8';
+                    },
+                    {
+                        name: 'reserve';
+                        type: {
+                            array: ['u128', 4];
+                        };
+                    },
+                ];
+            };
+        },
+        {
+            name: 'ticketNonce';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'vault';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'recipient';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'nonce';
+                        type: 'u64';
+                    },
+                    {
+                        name: 'usedAt';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'reserve';
+                        type: {
+                            array: ['u128', 2];
+                        };
+                    },
+                ];
+            };
+        },
+        {
+            name: 'vault';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'operator';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'bump';
+                        type: 'u8';
+                    },
+                    {
+                        name: 'seed';
+                        type: 'string';
+                    },
+                    {
+                        name: 'voucherCount';
+                        type: 'u64';
+                    },
+                    {
+                        name: 'soulbound';
+                        type: 'bool';
+                    },
+                    {
+                        name: 'voucherCountTracked';
+                        type: 'bool';
+                    },
+                    {
+                        name: 'padding';
+                        type: {
+                            array: ['u8', 6];
+                        };
+                    },
+                    {
+                        name: 'reserve';
+                        type: {
+                            array: ['u128', 5];
+                        };
+                    },
+                ];
+            };
+        },
+        {
+            name: 'fixedCreditVoucher';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'header';
+                        type: {
+                            defined: 'VoucherHeader';
+                        };
+                    },
+                    {
+                        name: 'currencyMint';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'creditAmount';
+                        type: 'u64';
+                    },
+                    {
+                        name: 'remainingCredit';
+                        type: 'u64';
+                    },
+                    {
+                        name: 'reserve';
+                        type: {
+                            array: ['u128', 4];
+                        };
+                    },
+                ];
+            };
+        },
+        {
+            name: 'interestRateVoucher';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'header';
+                        type: {
+                            defined: 'VoucherHeader';
+                        };
+                    },
+                    {
+                        name: 'rateReductionBps';
+                        type: 'u16';
+                    },
+                    {
+                        name: 'reserve';
+                        type: {
+                            array: ['u128', 4];
+                        };
+                    },
+                ];
+            };
+        },
+        {
+            name: 'repayVoucher';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'discountPercentage';
+                        type: 'u16';
+                    },
+                    {
+                        name: 'maximumAmount';
+                        type: 'u32';
+                    },
+                    {
+                        name: 'startTime';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'endTime';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'nftMint';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'authorator';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'redeemedBy';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'redeemedAt';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'vault';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'revoked';
+                        type: 'bool';
+                    },
+                    {
+                        name: 'maxUses';
+                        type: 'u16';
+                    },
+                    {
+                        name: 'usesRemaining';
+                        type: 'u16';
+                    },
+                    {
+                        name: 'padding';
+                        type: {
+                            array: ['u8', 3];
+                        };
+                    },
+                    {
+                        name: 'amountUsed';
+                        type: 'u64';
+                    },
+                    {
+                        name: 'amountRemaining';
+                        type: 'u64';
+                    },
+                ];
+            };
+        },
+    ];
+    types: [
+        {
+            name: 'AddFixedCreditVoucherParams';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'creditAmount';
+                        type: 'u64';
+                    },
+                    {
+                        name: 'startTime';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'endTime';
+                        type: 'i64';
+                    },
+                ];
+            };
+        },
+        {
+            name: 'AddInterestRateVoucherParams';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'rateReductionBps';
+                        type: 'u16';
+                    },
+                    {
+                        name: 'startTime';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'endTime';
+                        type: 'i64';
+                    },
+                ];
+            };
+        },
+        {
+            name: 'AddRepayVoucherParams';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'discountPercentage';
+                        type: 'u16';
+                    },
+                    {
+                        name: 'maximumAmount';
+                        type: 'u32';
+                    },
+                    {
+                        name: 'maxUses';
+                        type: 'u16';
+                    },
+                    {
+                        name: 'startTime';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'endTime';
+                        type: 'i64';
+                    },
+                ];
+            };
+        },
+        {
+            name: 'UpdateRepayVoucherParams';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'discountPercentage';
+                        type: 'u16';
+                    },
+                    {
+                        name: 'maximumAmount';
+                        type: 'u32';
+                    },
+                    {
+                        name: 'startTime';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'endTime';
+                        type: 'i64';
+                    },
+                ];
+            };
+        },
+        {
+            name: 'ClaimTicket';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'vault';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'mint';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'recipient';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'expiry';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'nonce';
+                        type: 'u64';
+                    },
+                ];
+            };
+        },
+        {
+            name: 'MetadataParams';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'name';
+                        type: 'string';
+                    },
+                    {
+                        name: 'symbol';
+                        type: 'string';
+                    },
+                    {
+                        name: 'uri';
+                        type: 'string';
+                    },
+                ];
+            };
+        },
+        {
+            name: 'ApplyRepayDiscountParams';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'amount';
+                        type: 'u64';
+                    },
+                    {
+                        name: 'consume';
+                        type: 'bool';
+                    },
+                ];
+            };
+        },
+        {
+            name: 'SetPauseParams';
+            type: {
+                kind: 'struct';
+                fields: [
+                    {
+                        name: 'paused';
+                        type: 'bool';
+                    },
+                    {
+                        name: 'mintPaused';
+                        type: 'bool';
+                    },
+                    {
+                        name: 'airdropPaused';
+                        type: 'bool';
+                    },
+                    {
+                        name: 'redeemPaused';
+                        type: 'bool';
+                    },
+                ];
+            };
+        },
+        {
+            name: 'VoucherKind';
+            type: {
+                kind: 'enum';
+                variants: [
+                    {
+                        name: 'Repay';
+                    },
+                    {
+                        name: 'InterestRate';
+                    },
+                    {
+                        name: 'FixedCredit';
+                    },
+                ];
+            };
+        },
+        {
+            name: 'VoucherStatus';
+            type: {
+                kind: 'enum';
+                variants: [
+                    {
+                        name: 'Active';
+                    },
+                    {
+                        name: 'Redeemed';
+                    },
+                    {
+                        name: 'Revoked';
+                    },
+                ];
+            };
+        },
+        {
+            name: 'VoucherHeader';
+            type: {
                 kind: 'struct';
                 fields: [
                     {
-                        name: 'bump';
-                        type: 'u8';
+                        name: 'kind';
+                        type: {
+                            defined: 'VoucherKind';
+                        };
+                    },
+                    {
+                        name: 'nftMint';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'authorator';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'vault';
+                        type: 'publicKey';
+                    },
+                    {
+                        name: 'startTime';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'endTime';
+                        type: 'i64';
+                    },
+                    {
+                        name: 'status';
+                        type: {
+                            defined: 'VoucherStatus';
+                        };
+                    },
+                ];
+            };
+        },
+        {
+            name: 'VoucherNftError';
+            type: {
+                kind: 'enum';
+                variants: [
+                    {
+                        name: 'OnlyAdmin';
+                    },
+                    {
+                        name: 'OnlyOperator';
+                    },
+                    {
+                        name: 'InvalidAccountArgument';
+                    },
+                    {
+                        name: 'AccountNotInitialized';
+                    },
+                    {
+                        name: 'AuthoratorNotSigned';
+                    },
+                    {
+                        name: 'VaultNotSigned';
+                    },
+                    {
+                        name: 'InvalidDiscountPercentage';
+                    },
+                    {
+                        name: 'InvalidMaximumAmount';
+                    },
+                    {
+                        name: 'StartTimeAfterEndTime';
+                    },
+                    {
+                        name: 'StartTimeBeforeCurrentTime';
+                    },
+                    {
+                        name: 'InvalidNftMint';
+                    },
+                    {
+                        name: 'VoucherNotStarted';
+                    },
+                    {
+                        name: 'VoucherExpired';
+                    },
+                    {
+                        name: 'InvalidVoucherHolder';
+                    },
+                    {
+                        name: 'OnlyPendingAdmin';
+                    },
+                    {
+                        name: 'VaultNotEmpty';
+                    },
+                    {
+                        name: 'MathOverflow';
+                    },
+                    {
+                        name: 'ProgramPaused';
+                    },
+                    {
+                        name: 'InvalidPermissions';
+                    },
+                    {
+                        name: 'InvalidMerkleProof';
+                    },
+                    {
+                        name: 'ClaimWindowClosed';
+                    },
+                    {
+                        name: 'ClaimWindowNotClosed';
+                    },
+                    {
+                        name: 'InvalidTicketSignature';
+                    },
+                    {
+                        name: 'TicketExpired';
+                    },
+                    {
+                        name: 'VoucherAlreadyActive';
+                    },
+                    {
+                        name: 'InvalidEndTime';
+                    },
+                    {
+                        name: 'VoucherNotClosable';
+                    },
+                    {
+                        name: 'VoucherRevoked';
+                    },
+                    {
+                        name: 'RecipientNotSigned';
+                    },
+                    {
+                        name: 'InvalidRateReduction';
+                    },
+                    {
+                        name: 'InvalidVoucherDuration';
+                    },
+                    {
+                        name: 'InvalidCreditAmount';
+                    },
+                    {
+                        name: 'InvalidCurrencyMint';
+                    },
+                    {
+                        name: 'VaultCountNotTracked';
+                    },
+                    {
+                        name: 'VoucherAlreadyRegistered';
+                    },
+                ];
+            };
+        },
+    ];
+    events: [
+        {
+            name: 'AdminProposed';
+            fields: [
+                {
+                    name: 'admin';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'pendingAdmin';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'AdminAccepted';
+            fields: [
+                {
+                    name: 'previousAdmin';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'admin';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'ConfigInitialized';
+            fields: [
+                {
+                    name: 'admin';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'collection';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'PauseUpdated';
+            fields: [
+                {
+                    name: 'paused';
+                    type: 'bool';
+                    index: false;
+                },
+                {
+                    name: 'mintPaused';
+                    type: 'bool';
+                    index: false;
+                },
+                {
+                    name: 'airdropPaused';
+                    type: 'bool';
+                    index: false;
+                },
+                {
+                    name: 'redeemPaused';
+                    type: 'bool';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'VaultAdded';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'seed';
+                    type: 'string';
+                    index: false;
+                },
+                {
+                    name: 'operator';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'VaultOperatorUpdated';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'previousOperator';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'operator';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'VaultSoulboundUpdated';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'soulbound';
+                    type: 'bool';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'VaultClosed';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'OperatorRoleGranted';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'operator';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'granted';
+                    type: 'u8';
+                    index: false;
+                },
+                {
+                    name: 'permissions';
+                    type: 'u8';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'OperatorRoleRevoked';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'operator';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'revoked';
+                    type: 'u8';
+                    index: false;
+                },
+                {
+                    name: 'permissions';
+                    type: 'u8';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'VoucherMinted';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'mint';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'owner';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'RepayVoucherRegistered';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'mint';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'discountPercentage';
+                    type: 'u16';
+                    index: false;
+                },
+                {
+                    name: 'maximumAmount';
+                    type: 'u32';
+                    index: false;
+                },
+                {
+                    name: 'maxUses';
+                    type: 'u16';
+                    index: false;
+                },
+                {
+                    name: 'startTime';
+                    type: 'i64';
+                    index: false;
+                },
+                {
+                    name: 'endTime';
+                    type: 'i64';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'RepayVoucherUpdated';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'mint';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'discountPercentage';
+                    type: 'u16';
+                    index: false;
+                },
+                {
+                    name: 'maximumAmount';
+                    type: 'u32';
+                    index: false;
+                },
+                {
+                    name: 'startTime';
+                    type: 'i64';
+                    index: false;
+                },
+                {
+                    name: 'endTime';
+                    type: 'i64';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'RepayVoucherClosed';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'mint';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'burned';
+                    type: 'bool';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'InterestRateVoucherRegistered';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'mint';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'rateReductionBps';
+                    type: 'u16';
+                    index: false;
+                },
+                {
+                    name: 'startTime';
+                    type: 'i64';
+                    index: false;
+                },
+                {
+                    name: 'endTime';
+                    type: 'i64';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'FixedCreditVoucherRegistered';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'mint';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'currencyMint';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'creditAmount';
+                    type: 'u64';
+                    index: false;
+                },
+                {
+                    name: 'startTime';
+                    type: 'i64';
+                    index: false;
+                },
+                {
+                    name: 'endTime';
+                    type: 'i64';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'VoucherAirdropped';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'mint';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'recipient';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'ClaimDistributorCreated';
+            fields: [
+                {
+                    name: 'distributor';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'merkleRoot';
+                    type: {
+                        array: ['u8', 32];
+                    };
+                    index: false;
+                },
+                {
+                    name: 'endTime';
+                    type: 'i64';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'VoucherClaimed';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'mint';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'recipient';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'distributor';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'DistributorClawedBack';
+            fields: [
+                {
+                    name: 'distributor';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'claimedCount';
+                    type: 'u64';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'VoucherRevokeUpdated';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'mint';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'holder';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'revoked';
+                    type: 'bool';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'RepayVoucherRedeemed';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'mint';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'holder';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'amount';
+                    type: 'u64';
+                    index: false;
+                },
+                {
+                    name: 'discount';
+                    type: 'u64';
+                    index: false;
+                },
+                {
+                    name: 'usesRemaining';
+                    type: 'u16';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+        {
+            name: 'FixedCreditVoucherRedeemed';
+            fields: [
+                {
+                    name: 'vault';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'mint';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'holder';
+                    type: 'publicKey';
+                    index: false;
+                },
+                {
+                    name: 'credit';
+                    type: 'u64';
+                    index: false;
+                },
+                {
+                    name: 'remainingCredit';
+                    type: 'u64';
+                    index: false;
+                },
+                {
+                    name: 'timestamp';
+                    type: 'i64';
+                    index: false;
+                },
+            ];
+        },
+    ];
+    errors: [
+        {
+            code: 6000;
+            name: 'OnlyAdmin';
+            msg: 'Only Admin';
+        },
+        {
+            code: 6001;
+            name: 'OnlyOperator';
+            msg: 'Only Operator';
+        },
+        {
+            code: 6002;
+            name: 'InvalidAccountArgument';
+            msg: 'Invalid Account Argument';
+        },
+        {
+            code: 6003;
+            name: 'AccountNotInitialized';
+            msg: 'Account Not Initialized';
+        },
+        {
+            code: 6004;
+            name: 'AuthoratorNotSigned';
+            msg: 'Authorator Not Signed';
+        },
+        {
+            code: 6005;
+            name: 'VaultNotSigned';
+            msg: 'Vault Not Signed';
+        },
+        {
+            code: 6006;
+            name: 'InvalidDiscountPercentage';
+            msg: 'Invalid Discount Percentage';
+        },
+        {
+            code: 6007;
+            name: 'InvalidMaximumAmount';
+            msg: 'Invalid Maximum Amount';
+        },
+        {
+            code: 6008;
+            name: 'StartTimeAfterEndTime';
+            msg: 'StartTime After EndTime';
+        },
+        {
+            code: 6009;
+            name: 'StartTimeBeforeCurrentTime';
+            msg: 'StartTime Before CurrentTime';
+        },
+        {
+            code: 6010;
+            name: 'InvalidNftMint';
+            msg: 'Invalid Nft Mint';
+        },
+        {
+            code: 6011;
+            name: 'VoucherNotStarted';
+            msg: 'Voucher Not Started';
+        },
+        {
+            code: 6012;
+            name: 'VoucherExpired';
+            msg: 'Voucher Expired';
+        },
+        {
+            code: 6013;
+            name: 'InvalidVoucherHolder';
+            msg: 'Invalid Voucher Holder';
+        },
+        {
+            code: 6014;
+            name: 'OnlyPendingAdmin';
+            msg: 'Only Pending Admin';
+        },
+        {
+            code: 6015;
+            name: 'VaultNotEmpty';
+            msg: 'Vault Not Empty';
+        },
+        {
+            code: 6016;
+            name: 'MathOverflow';
+            msg: 'Math Overflow';
+        },
+        {
+            code: 6017;
+            name: 'ProgramPaused';
+            msg: 'Program Paused';
+        },
+        {
+            code: 6018;
+            name: 'InvalidPermissions';
+            msg: 'Invalid Permissions';
+        },
+        {
+            code: 6019;
+            name: 'InvalidMerkleProof';
+            msg: 'Invalid Merkle Proof';
+        },
+        {
+            code: 6020;
+            name: 'ClaimWindowClosed';
+            msg: 'Claim Window Closed';
+        },
+        {
+            code: 6021;
+            name: 'ClaimWindowNotClosed';
+            msg: 'Claim Window Not Closed';
+        },
+        {
+            code: 6022;
+            name: 'InvalidTicketSignature';
+            msg: 'Invalid Ticket Signature';
+        },
+        {
+            code: 6023;
+            name: 'TicketExpired';
+            msg: 'Ticket Expired';
+        },
+        {
+            code: 6024;
+            name: 'VoucherAlreadyActive';
+            msg: 'Voucher Already Active';
+        },
+        {
+            code: 6025;
+            name: 'InvalidEndTime';
+            msg: 'Invalid End Time';
+        },
+        {
+            code: 6026;
+            name: 'VoucherNotClosable';
+            msg: 'Voucher Not Closable';
+        },
+        {
+            code: 6027;
+            name: 'VoucherRevoked';
+            msg: 'Voucher Revoked';
+        },
+        {
+            code: 6028;
+            name: 'RecipientNotSigned';
+            msg: 'Recipient Not Signed';
+        },
+        {
+            code: 6029;
+            name: 'InvalidRateReduction';
+            msg: 'Invalid Rate Reduction';
+        },
+        {
+            code: 6030;
+            name: 'InvalidVoucherDuration';
+            msg: 'Invalid Voucher Duration';
+        },
+        {
+            code: 6031;
+            name: 'InvalidCreditAmount';
+            msg: 'Invalid Credit Amount';
+        },
+        {
+            code: 6032;
+            name: 'InvalidCurrencyMint';
+            msg: 'Invalid Currency Mint';
+        },
+        {
+            code: 6033;
+            name: 'VaultCountNotTracked';
+            msg: 'Vault Count Not Tracked';
+        },
+        {
+            code: 6034;
+            name: 'VoucherAlreadyRegistered';
+            msg: 'Voucher Already Registered';
+        },
+    ];
+};
+
+export const IDL: VoucherNft = {
+    version: '0.1.0',
+    name: 'voucher_nft',
+    instructions: [
+        {
+            name: 'initialize',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'authorator',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'relendCollection',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'authoratorTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'admin',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'associatedTokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'rent',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'params',
+                    type: {
+                        defined: 'MetadataParams',
+                    },
+                },
+            ],
+        },
+        {
+            name: 'proposeAdmin',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'admin',
+                    isMut: false,
+                    isSigner: true,
+                },
+            ],
+            args: [
+                {
+                    name: 'pendingAdmin',
+                    type: 'publicKey',
+                },
+            ],
+        },
+        {
+            name: 'acceptAdmin',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'pendingAdmin',
+                    isMut: false,
+                    isSigner: true,
+                },
+            ],
+            args: [],
+        },
+        {
+            name: 'setPause',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'admin',
+                    isMut: false,
+                    isSigner: true,
+                },
+            ],
+            args: [
+                {
+                    name: 'params',
+                    type: {
+                        defined: 'SetPauseParams',
+                    },
+                },
+            ],
+        },
+        {
+            name: 'addVault',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'admin',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'seed',
+                    type: 'string',
+                },
+                {
+                    name: 'operator',
+                    type: 'publicKey',
+                },
+            ],
+        },
+        {
+            name: 'setVaultOperator',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'admin',
+                    isMut: false,
+                    isSigner: true,
+                },
+            ],
+            args: [
+                {
+                    name: 'operator',
+                    type: 'publicKey',
+                },
+            ],
+        },
+        {
+            name: 'setVaultSoulbound',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'admin',
+                    isMut: false,
+                    isSigner: true,
+                },
+            ],
+            args: [
+                {
+                    name: 'soulbound',
+                    type: 'bool',
+                },
+            ],
+        },
+        {
+            name: 'closeVault',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'admin',
+                    isMut: true,
+                    isSigner: true,
+                },
+            ],
+            args: [],
+        },
+        {
+            name: 'grantOperatorRole',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'admin',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'operator',
+                    type: 'publicKey',
+                },
+                {
+                    name: 'permissions',
+                    type: 'u8',
+                },
+            ],
+        },
+        {
+            name: 'revokeOperatorRole',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'admin',
+                    isMut: false,
+                    isSigner: true,
+                },
+            ],
+            args: [
+                {
+                    name: 'permissions',
+                    type: 'u8',
+                },
+            ],
+        },
+        {
+            name: 'mintVoucher',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'authorator',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'mint',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'vaultTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'collectionMint',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'collectionMetadataAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'collectionMasterEdition',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'associatedTokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'rent',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'params',
+                    type: {
+                        defined: 'MetadataParams',
+                    },
+                },
+            ],
+        },
+        {
+            name: 'mintVoucherTo',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'authorator',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'mint',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'recipient',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'recipientTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'collectionMint',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'collectionMetadataAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'collectionMasterEdition',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'associatedTokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'rent',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'params',
+                    type: {
+                        defined: 'MetadataParams',
+                    },
+                },
+            ],
+        },
+        {
+            name: 'mintRepayVoucher',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'authorator',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'mint',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'vaultTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'collectionMint',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'collectionMetadataAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'collectionMasterEdition',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'repayVoucher',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'associatedTokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'rent',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'metadataParams',
+                    type: {
+                        defined: 'MetadataParams',
+                    },
+                },
+                {
+                    name: 'repayVoucherParams',
+                    type: {
+                        defined: 'AddRepayVoucherParams',
+                    },
+                },
+            ],
+        },
+        {
+            name: 'addVoucherRepayInformation',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'mint',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'repayVoucher',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'interestRateVoucher',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'fixedCreditVoucher',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'params',
+                    type: {
+                        defined: 'AddRepayVoucherParams',
+                    },
+                },
+            ],
+        },
+        {
+            name: 'addVoucherInterestInformation',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'mint',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'interestRateVoucher',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'repayVoucher',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'fixedCreditVoucher',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'params',
+                    type: {
+                        defined: 'AddInterestRateVoucherParams',
+                    },
+                },
+            ],
+        },
+        {
+            name: 'addVoucherFixedCreditInformation',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'mint',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'currencyMint',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'fixedCreditVoucher',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'repayVoucher',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'interestRateVoucher',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'params',
+                    type: {
+                        defined: 'AddFixedCreditVoucherParams',
+                    },
+                },
+            ],
+        },
+        {
+            name: 'updateRepayVoucher',
+            accounts: [
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: false,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'repayVoucher',
+                    isMut: true,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'params',
+                    type: {
+                        defined: 'UpdateRepayVoucherParams',
+                    },
+                },
+            ],
+        },
+        {
+            name: 'closeRepayVoucher',
+            accounts: [
+                {
+                    name: 'vault',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'mint',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'vaultTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'repayVoucher',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'burnVaultVoucher',
+                    type: 'bool',
+                },
+            ],
+        },
+        {
+            name: 'operatorAirdrop',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'user',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'mint',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vaultTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'userTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'associatedTokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'rent',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [],
+        },
+        {
+            name: 'batchOperatorAirdrop',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'associatedTokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'rent',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [],
+        },
+        {
+            name: 'getInterestRateReduction',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'holder',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'mint',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'holderTokenAccount',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'interestRateVoucher',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [],
+        },
+        {
+            name: 'revokeVoucher',
+            accounts: [
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: false,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'mint',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'holderTokenAccount',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'voucherInformation',
+                    isMut: true,
+                    isSigner: false,
+                },
+            ],
+            args: [],
+        },
+        {
+            name: 'unrevokeVoucher',
+            accounts: [
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: false,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'mint',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'holderTokenAccount',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'voucherInformation',
+                    isMut: true,
+                    isSigner: false,
+                },
+            ],
+            args: [],
+        },
+        {
+            name: 'createClaimDistributor',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'distributor',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'merkleRoot',
+                    type: {
+                        array: ['u8', 32],
+                    },
+                },
+                {
+                    name: 'endTime',
+                    type: 'i64',
+                },
+            ],
+        },
+        {
+            name: 'claimVoucher',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'distributor',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'user',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'mint',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vaultTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'userTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'claimStatus',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'associatedTokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'rent',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'proof',
+                    type: {
+                        vec: {
+                            array: ['u8', 32],
+                        },
+                    },
+                },
+            ],
+        },
+        {
+            name: 'claimWithTicket',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'ticketSigner',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'recipient',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'mint',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vaultTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'recipientTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'ticketNonce',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'instructionsSysvar',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'associatedTokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'systemProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'rent',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'ticket',
+                    type: {
+                        defined: 'ClaimTicket',
+                    },
+                },
+            ],
+        },
+        {
+            name: 'clawback',
+            accounts: [
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'operator',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'operatorRole',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'distributor',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'payer',
+                    isMut: true,
+                    isSigner: false,
+                },
+            ],
+            args: [],
+        },
+        {
+            name: 'redeemRepayVoucher',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'holder',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'mint',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'holderTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'repayVoucher',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'amount',
+                    type: 'u64',
+                },
+            ],
+        },
+        {
+            name: 'redeemFixedCreditVoucher',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'holder',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'mint',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'holderTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'fixedCreditVoucher',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'currencyMint',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'amount',
+                    type: 'u64',
+                },
+            ],
+        },
+        {
+            name: 'applyRepayDiscount',
+            accounts: [
+                {
+                    name: 'config',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'vault',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'holder',
+                    isMut: true,
+                    isSigner: true,
+                },
+                {
+                    name: 'mint',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'holderTokenAccount',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'repayVoucher',
+                    isMut: true,
+                    isSigner: false,
+                },
+                {
+                    name: 'metadataAccount',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'masterEdition',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenMetadataProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+                {
+                    name: 'tokenProgram',
+                    isMut: false,
+                    isSigner: false,
+                },
+            ],
+            args: [
+                {
+                    name: 'params',
+                    type: {
+                        defined: 'ApplyRepayDiscountParams',
+                    },
+                },
+            ],
+        },
+    ],
+    accounts: [
+        {
+            name: 'authorator',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'bump',
+                        type: 'u8',
+                    },
+                ],
+            },
+        },
+        {
+            name: 'claimDistributor',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'vault',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'merkleRoot',
+                        type: {
+                            array: ['u8', 32],
+                        },
+                    },
+                    {
+                        name: 'endTime',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'claimedCount',
+                        type: 'u64',
+                    },
+                    {
+                        name: 'bump',
+                        type: 'u8',
+                    },
+                    {
+                        name: 'payer',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'reserve',
+                        type: {
+                            array: ['u128', 2],
+                        },
+                    },
+                ],
+            },
+        },
+        {
+            name: 'claimStatus',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'distributor',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'user',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'mint',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'claimedAt',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'reserve',
+                        type: {
+                            array: ['u128', 2],
+                        },
+                    },
+                ],
+            },
+        },
+        {
+            name: 'config',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'admin',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'collection',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'pendingAdmin',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'paused',
+                        type: 'bool',
+                    },
+                    {
+                        name: 'mintPaused',
+                        type: 'bool',
+                    },
+                    {
+                        name: 'airdropPaused',
+                        type: 'bool',
+                    },
+                    {
+                        name: 'redeemPaused',
+                        type: 'bool',
+                    },
+                    {
+                        name: 'padding',
+                        type: {
+                            array: ['u8', 12],
+                        },
+                    },
+                    {
+                        name: 'reserve',
+                        type: {
+                            array: ['u128', 3],
+                        },
+                    },
+                ],
+            },
+        },
+        {
+            name: 'operatorRole',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'vault',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'operator',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'permissions',
+                        type: 'u8',
+                    },
+                    {
+                        name: 'bump',
+                        type: 'u8',
+                    },
+                    {
+                        name: 'reserve',
+                        type: {
+                            array: ['u128', 4],
+                        },
+                    },
+                ],
+            },
+        },
+        {
+            name: 'ticketNonce',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'vault',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'recipient',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'nonce',
+                        type: 'u64',
+                    },
+                    {
+                        name: 'usedAt',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'reserve',
+                        type: {
+                            array: ['u128', 2],
+                        },
+                    },
+                ],
+            },
+        },
+        {
+            name: 'vault',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'operator',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'bump',
+                        type: 'u8',
+                    },
+                    {
+                        name: 'seed',
+                        type: 'string',
+                    },
+                    {
+                        name: 'voucherCount',
+                        type: 'u64',
+                    },
+                    {
+                        name: 'soulbound',
+                        type: 'bool',
+                    },
+                    {
+                        name: 'voucherCountTracked',
+                        type: 'bool',
+                    },
+                    {
+                        name: 'padding',
+                        type: {
+                            array: ['u8', 6],
+                        },
+                    },
+                    {
+                        name: 'reserve',
+                        type: {
+                            array: ['u128', 5],
+                        },
+                    },
+                ],
+            },
+        },
+        {
+            name: 'fixedCreditVoucher',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'header',
+                        type: {
+                            defined: 'VoucherHeader',
+                        },
+                    },
+                    {
+                        name: 'currencyMint',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'creditAmount',
+                        type: 'u64',
+                    },
+                    {
+                        name: 'remainingCredit',
+                        type: 'u64',
+                    },
+                    {
+                        name: 'reserve',
+                        type: {
+                            array: ['u128', 4],
+                        },
+                    },
+                ],
+            },
+        },
+        {
+            name: 'interestRateVoucher',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'header',
+                        type: {
+                            defined: 'VoucherHeader',
+                        },
+                    },
+                    {
+                        name: 'rateReductionBps',
+                        type: 'u16',
+                    },
+                    {
+                        name: 'reserve',
+                        type: {
+                            array: ['u128', 4],
+                        },
+                    },
+                ],
+            },
+        },
+        {
+            name: 'repayVoucher',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'discountPercentage',
+                        type: 'u16',
+                    },
+                    {
+                        name: 'maximumAmount',
+                        type: 'u32',
+                    },
+                    {
+                        name: 'startTime',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'endTime',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'nftMint',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'authorator',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'redeemedBy',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'redeemedAt',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'vault',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'revoked',
+                        type: 'bool',
+                    },
+                    {
+                        name: 'maxUses',
+                        type: 'u16',
+                    },
+                    {
+                        name: 'usesRemaining',
+                        type: 'u16',
+                    },
+                    {
+                        name: 'padding',
+                        type: {
+                            array: ['u8', 3],
+                        },
+                    },
+                    {
+                        name: 'amountUsed',
+                        type: 'u64',
+                    },
+                    {
+                        name: 'amountRemaining',
+                        type: 'u64',
+                    },
+                ],
+            },
+        },
+    ],
+    types: [
+        {
+            name: 'AddFixedCreditVoucherParams',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'creditAmount',
+                        type: 'u64',
                     },
-                ];
-            };
+                    {
+                        name: 'startTime',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'endTime',
+                        type: 'i64',
+                    },
+                ],
+            },
         },
         {
-            name: 'config';
+            name: 'AddInterestRateVoucherParams',
             type: {
-                kind: 'struct';
+                kind: 'struct',
                 fields: [
                     {
-                        name: 'admin';
-                        type: 'publicKey';
+                        name: 'rateReductionBps',
+                        type: 'u16',
                     },
                     {
-                        name: 'collection';
-                        type: 'publicKey';
+                        name: 'startTime',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'endTime',
+                        type: 'i64',
+                    },
+                ],
+            },
+        },
+        {
+            name: 'AddRepayVoucherParams',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'discountPercentage',
+                        type: 'u16',
+                    },
+                    {
+                        name: 'maximumAmount',
+                        type: 'u32',
+                    },
+                    {
+                        name: 'maxUses',
+                        type: 'u16',
+                    },
+                    {
+                        name: 'startTime',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'endTime',
+                        type: 'i64',
+                    },
+                ],
+            },
+        },
+        {
+            name: 'UpdateRepayVoucherParams',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'discountPercentage',
+                        type: 'u16',
+                    },
+                    {
+                        name: 'maximumAmount',
+                        type: 'u32',
+                    },
+                    {
+                        name: 'startTime',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'endTime',
+                        type: 'i64',
+                    },
+                ],
+            },
+        },
+        {
+            name: 'ClaimTicket',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'vault',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'mint',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'recipient',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'expiry',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'nonce',
+                        type: 'u64',
+                    },
+                ],
+            },
+        },
+        {
+            name: 'MetadataParams',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'name',
+                        type: 'string',
+                    },
+                    {
+                        name: 'symbol',
+                        type: 'string',
+                    },
+                    {
+                        name: 'uri',
+                        type: 'string',
+                    },
+                ],
+            },
+        },
+        {
+            name: 'ApplyRepayDiscountParams',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'amount',
+                        type: 'u64',
+                    },
+                    {
+                        name: 'consume',
+                        type: 'bool',
+                    },
+                ],
+            },
+        },
+        {
+            name: 'SetPauseParams',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'paused',
+                        type: 'bool',
+                    },
+                    {
+                        name: 'mintPaused',
+                        type: 'bool',
+                    },
+                    {
+                        name: 'airdropPaused',
+                        type: 'bool',
+                    },
+                    {
+                        name: 'redeemPaused',
+                        type: 'bool',
+                    },
+                ],
+            },
+        },
+        {
+            name: 'VoucherKind',
+            type: {
+                kind: 'enum',
+                variants: [
+                    {
+                        name: 'Repay',
+                    },
+                    {
+                        name: 'InterestRate',
+                    },
+                    {
+                        name: 'FixedCredit',
+                    },
+                ],
+            },
+        },
+        {
+            name: 'VoucherStatus',
+            type: {
+                kind: 'enum',
+                variants: [
+                    {
+                        name: 'Active',
+                    },
+                    {
+                        name: 'Redeemed',
+                    },
+                    {
+                        name: 'Revoked',
+                    },
+                ],
+            },
+        },
+        {
+            name: 'VoucherHeader',
+            type: {
+                kind: 'struct',
+                fields: [
+                    {
+                        name: 'kind',
+                        type: {
+                            defined: 'VoucherKind',
+                        },
+                    },
+                    {
+                        name: 'nftMint',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'authorator',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'vault',
+                        type: 'publicKey',
+                    },
+                    {
+                        name: 'startTime',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'endTime',
+                        type: 'i64',
+                    },
+                    {
+                        name: 'status',
+                        type: {
+                            defined: 'VoucherStatus',
+                        },
+                    },
+                ],
+            },
+        },
+        {
+            name: 'VoucherNftError',
+            type: {
+                kind: 'enum',
+                variants: [
+                    {
+                        name: 'OnlyAdmin',
+                    },
+                    {
+                        name: 'OnlyOperator',
+                    },
+                    {
+                        name: 'InvalidAccountArgument',
+                    },
+                    {
+                        name: 'AccountNotInitialized',
+                    },
+                    {
+                        name: 'AuthoratorNotSigned',
                     },
                     {
-                        name: 'reserve';
-                        type: {
-                            array: ['u128', 6];
-                        };
+                        name: 'VaultNotSigned',
                     },
-                ];
-            };
-        },
-        {
-            name: 'vault';
-            type: {
-                kind: 'struct';
-                fields: [
                     {
-                        name: 'operator';
-                        type: 'publicKey';
+                        name: 'InvalidDiscountPercentage',
                     },
                     {
-                        name: 'bump';
-                        type: 'u8';
+                        name: 'InvalidMaximumAmount',
                     },
                     {
-                        name: 'seed';
-                        type: 'string';
+                        name: 'StartTimeAfterEndTime',
                     },
                     {
-                        name: 'reserve';
-                        type: {
-                            array: ['u128', 6];
-                        };
+                        name: 'StartTimeBeforeCurrentTime',
                     },
-                ];
-            };
-        },
-        {
-            name: 'repayVoucher';
-            type: {
-                kind: 'struct';
-                fields: [
                     {
-                        name: 'discountPercentage';
-                        type: 'u16';
+                        name: 'InvalidNftMint',
                     },
                     {
-                        name: 'maximumAmount';
-                        type: 'u32';
+                        name: 'VoucherNotStarted',
                     },
                     {
-                        name: 'startTime';
-                        type: 'i64';
+                        name: 'VoucherExpired',
                     },
                     {
-                        name: 'endTime';
-                        type: 'i64';
+                        name: 'InvalidVoucherHolder',
                     },
                     {
-                        name: 'nftMint';
-                        type: 'publicKey';
+                        name: 'OnlyPendingAdmin',
                     },
                     {
-                        name: 'authorator';
-                        type: 'publicKey';
+                        name: 'VaultNotEmpty',
                     },
                     {
-                        name: 'reserve';
-                        type: {
-                            array: ['u128', 6];
-                        };
+                        name: 'MathOverflow',
                     },
-                ];
-            };
-        },
-    ];
-    types: [
-        {
-            name: 'AddRepayVoucherParams';
-            type: {
-                kind: 'struct';
-                fields: [
                     {
-                        name: 'discountPercentage';
-                        type: 'u16';
+                        name: 'ProgramPaused',
                     },
                     {
-                        name: 'maximumAmount';
-                        type: 'u32';
+                        name: 'InvalidPermissions',
                     },
                     {
-                        name: 'startTime';
-                        type: 'i64';
+                        name: 'InvalidMerkleProof',
                     },
                     {
-                        name: 'endTime';
-                        type: 'i64';
+                        name: 'ClaimWindowClosed',
                     },
-                ];
-            };
-        },
-        {
-            name: 'MetadataParams';
-            type: {
-                kind: 'struct';
-                fields: [
                     {
-                        name: 'name';
-                        type: 'string';
+                        name: 'ClaimWindowNotClosed',
                     },
                     {
-                        name: 'symbol';
-                        type: 'string';
+                        name: 'InvalidTicketSignature',
                     },
                     {
-                        name: 'uri';
-                        type: 'string';
+                        name: 'TicketExpired',
                     },
-                ];
-            };
-        },
-        {
-            name: 'VoucherNftError';
-            type: {
-                kind: 'enum';
-                variants: [
                     {
-                        name: 'OnlyAdmin';
+                        name: 'VoucherAlreadyActive',
                     },
                     {
-                        name: 'OnlyOperator';
+                        name: 'InvalidEndTime',
                     },
                     {
-                        name: 'InvalidAccountArgument';
+                        name: 'VoucherNotClosable',
                     },
                     {
-                        name: 'AccountNotInitialized';
+                        name: 'VoucherRevoked',
                     },
                     {
-                        name: 'AuthoratorNotSigned';
+                        name: 'RecipientNotSigned',
                     },
                     {
-                        name: 'VaultNotSigned';
+                        name: 'InvalidRateReduction',
                     },
                     {
-                        name: 'InvalidDiscountPercentage';
+                        name: 'InvalidVoucherDuration',
                     },
                     {
-                        name: 'InvalidMaximumAmount';
+                        name: 'InvalidCreditAmount',
                     },
                     {
-                        name: 'StartTimeAfterEndTime';
+                        name: 'InvalidCurrencyMint',
                     },
                     {
-                        name: 'StartTimeBeforeCurrentTime';
+                        name: 'VaultCountNotTracked',
                     },
                     {
-                        name: 'InvalidNftMint';
+                        name: 'VoucherAlreadyRegistered',
                     },
-                ];
-            };
+                ],
+            },
+        },
+    ],
+    events: [
+        {
+            name: 'AdminProposed',
+            fields: [
+                {
+                    name: 'admin',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'pendingAdmin',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
+        },
+        {
+            name: 'AdminAccepted',
+            fields: [
+                {
+                    name: 'previousAdmin',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'admin',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
+        },
+        {
+            name: 'ConfigInitialized',
+            fields: [
+                {
+                    name: 'admin',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'collection',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
+        },
+        {
+            name: 'PauseUpdated',
+            fields: [
+                {
+                    name: 'paused',
+                    type: 'bool',
+                    index: false,
+                },
+                {
+                    name: 'mintPaused',
+                    type: 'bool',
+                    index: false,
+                },
+                {
+                    name: 'airdropPaused',
+                    type: 'bool',
+                    index: false,
+                },
+                {
+                    name: 'redeemPaused',
+                    type: 'bool',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
+        },
+        {
+            name: 'VaultAdded',
+            fields: [
+                {
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'seed',
+                    type: 'string',
+                    index: false,
+                },
+                {
+                    name: 'operator',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
+        },
+        {
+            name: 'VaultOperatorUpdated',
+            fields: [
+                {
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'previousOperator',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'operator',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
+        },
+        {
+            name: 'VaultSoulboundUpdated',
+            fields: [
+                {
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'soulbound',
+                    type: 'bool',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
+        },
+        {
+            name: 'VaultClosed',
+            fields: [
+                {
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
+        },
+        {
+            name: 'OperatorRoleGranted',
+            fields: [
+                {
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'operator',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'granted',
+                    type: 'u8',
+                    index: false,
+                },
+                {
+                    name: 'permissions',
+                    type: 'u8',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
+        },
+        {
+            name: 'OperatorRoleRevoked',
+            fields: [
+                {
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'operator',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'revoked',
+                    type: 'u8',
+                    index: false,
+                },
+                {
+                    name: 'permissions',
+                    type: 'u8',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
+        },
+        {
+            name: 'VoucherMinted',
+            fields: [
+                {
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'mint',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'owner',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
         },
-    ];
-};
-
-export const IDL: VoucherNft = {
-    version: '0.1.0',
-    name: 'voucher_nft',
-    instructions: [
         {
-            name: 'initialize',
-            accounts: [
+            name: 'RepayVoucherRegistered',
+            fields: [
                 {
-                    name: 'config',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'authorator',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'mint',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'relendCollection',
-                    isMut: true,
-                    isSigner: true,
+                    name: 'discountPercentage',
+                    type: 'u16',
+                    index: false,
                 },
                 {
-                    name: 'authoratorTokenAccount',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'maximumAmount',
+                    type: 'u32',
+                    index: false,
                 },
                 {
-                    name: 'metadataAccount',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'maxUses',
+                    type: 'u16',
+                    index: false,
                 },
                 {
-                    name: 'masterEdition',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'startTime',
+                    type: 'i64',
+                    index: false,
                 },
                 {
-                    name: 'admin',
-                    isMut: true,
-                    isSigner: true,
+                    name: 'endTime',
+                    type: 'i64',
+                    index: false,
                 },
                 {
-                    name: 'tokenMetadataProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
                 },
+            ],
+        },
+        {
+            name: 'RepayVoucherUpdated',
+            fields: [
                 {
-                    name: 'tokenProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'associatedTokenProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'mint',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'systemProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'discountPercentage',
+                    type: 'u16',
+                    index: false,
                 },
                 {
-                    name: 'rent',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'maximumAmount',
+                    type: 'u32',
+                    index: false,
                 },
-            ],
-            args: [
                 {
-                    name: 'params',
-                    type: {
-                        defined: 'MetadataParams',
-                    },
+                    name: 'startTime',
+                    type: 'i64',
+                    index: false,
+                },
+                {
+                    name: 'endTime',
+                    type: 'i64',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
                 },
             ],
         },
         {
-            name: 'addVault',
-            accounts: [
+            name: 'RepayVoucherClosed',
+            fields: [
                 {
-                    name: 'config',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'vault',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'mint',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'admin',
-                    isMut: true,
-                    isSigner: true,
+                    name: 'burned',
+                    type: 'bool',
+                    index: false,
                 },
                 {
-                    name: 'systemProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
                 },
             ],
-            args: [
+        },
+        {
+            name: 'InterestRateVoucherRegistered',
+            fields: [
                 {
-                    name: 'seed',
-                    type: 'string',
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'operator',
+                    name: 'mint',
                     type: 'publicKey',
+                    index: false,
                 },
-            ],
-        },
-        {
-            name: 'mintVoucher',
-            accounts: [
                 {
-                    name: 'config',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'rateReductionBps',
+                    type: 'u16',
+                    index: false,
                 },
                 {
-                    name: 'vault',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'startTime',
+                    type: 'i64',
+                    index: false,
                 },
                 {
-                    name: 'authorator',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'endTime',
+                    type: 'i64',
+                    index: false,
                 },
                 {
-                    name: 'operator',
-                    isMut: true,
-                    isSigner: true,
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
+        },
+        {
+            name: 'FixedCreditVoucherRegistered',
+            fields: [
+                {
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
                     name: 'mint',
-                    isMut: true,
-                    isSigner: true,
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'vaultTokenAccount',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'currencyMint',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'metadataAccount',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'creditAmount',
+                    type: 'u64',
+                    index: false,
                 },
                 {
-                    name: 'masterEdition',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'startTime',
+                    type: 'i64',
+                    index: false,
                 },
                 {
-                    name: 'collectionMint',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'endTime',
+                    type: 'i64',
+                    index: false,
                 },
                 {
-                    name: 'collectionMetadataAccount',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
                 },
+            ],
+        },
+        {
+            name: 'VoucherAirdropped',
+            fields: [
                 {
-                    name: 'collectionMasterEdition',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'tokenMetadataProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'mint',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'systemProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'recipient',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'tokenProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
                 },
+            ],
+        },
+        {
+            name: 'ClaimDistributorCreated',
+            fields: [
                 {
-                    name: 'associatedTokenProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'distributor',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'rent',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
                 },
-            ],
-            args: [
                 {
-                    name: 'params',
+                    name: 'merkleRoot',
                     type: {
-                        defined: 'MetadataParams',
+                        array: ['u8', 32],
                     },
+                    index: false,
+                },
+                {
+                    name: 'endTime',
+                    type: 'i64',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
                 },
             ],
         },
         {
-            name: 'addVoucherRepayInformation',
-            accounts: [
+            name: 'VoucherClaimed',
+            fields: [
                 {
                     name: 'vault',
-                    isMut: false,
-                    isSigner: false,
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'operator',
-                    isMut: true,
-                    isSigner: true,
+                    name: 'mint',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'mint',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'recipient',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'metadataAccount',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'distributor',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'masterEdition',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
                 },
+            ],
+        },
+        {
+            name: 'DistributorClawedBack',
+            fields: [
                 {
-                    name: 'repayVoucher',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'distributor',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'tokenMetadataProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'systemProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'claimedCount',
+                    type: 'u64',
+                    index: false,
                 },
-            ],
-            args: [
                 {
-                    name: 'params',
-                    type: {
-                        defined: 'AddRepayVoucherParams',
-                    },
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
                 },
             ],
         },
         {
-            name: 'operatorAirdrop',
-            accounts: [
+            name: 'VoucherRevokeUpdated',
+            fields: [
                 {
                     name: 'vault',
-                    isMut: false,
-                    isSigner: false,
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'operator',
-                    isMut: true,
-                    isSigner: true,
+                    name: 'mint',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'user',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'holder',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'revoked',
+                    type: 'bool',
+                    index: false,
+                },
+                {
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
+                },
+            ],
+        },
+        {
+            name: 'RepayVoucherRedeemed',
+            fields: [
+                {
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
                     name: 'mint',
-                    isMut: false,
-                    isSigner: false,
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'holder',
+                    type: 'publicKey',
+                    index: false,
+                },
+                {
+                    name: 'amount',
+                    type: 'u64',
+                    index: false,
+                },
+                {
+                    name: 'discount',
+                    type: 'u64',
+                    index: false,
+                },
+                {
+                    name: 'usesRemaining',
+                    type: 'u16',
+                    index: false,
                 },
                 {
-                    name: 'masterEdition',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
                 },
+            ],
+        },
+        {
+            name: 'FixedCreditVoucherRedeemed',
+            fields: [
                 {
-                    name: 'vaultTokenAccount',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'vault',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'userTokenAccount',
-                    isMut: true,
-                    isSigner: false,
+                    name: 'mint',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'associatedTokenProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'holder',
+                    type: 'publicKey',
+                    index: false,
                 },
                 {
-                    name: 'tokenProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'credit',
+                    type: 'u64',
+                    index: false,
                 },
                 {
-                    name: 'systemProgram',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'remainingCredit',
+                    type: 'u64',
+                    index: false,
                 },
                 {
-                    name: 'rent',
-                    isMut: false,
-                    isSigner: false,
+                    name: 'timestamp',
+                    type: 'i64',
+                    index: false,
                 },
             ],
-            args: [],
         },
     ],
-    accounts: [
+    errors: [
         {
-            name: 'authorator',
-            type: {
-                kind: 'struct',
-                fields: [
-                    {
-                        name: 'bump',
-                        type: 'u8',
-                    },
-                ],
-            },
+            code: 6000,
+            name: 'OnlyAdmin',
+            msg: 'Only Admin',
         },
         {
-            name: 'config',
-            type: {
-                kind: 'struct',
-                fields: [
-                    {
-                        name: 'admin',
-                        type: 'publicKey',
-                    },
-                    {
-                        name: 'collection',
-                        type: 'publicKey',
-                    },
-                    {
-                        name: 'reserve',
-                        type: {
-                            array: ['u128', 6],
-                        },
-                    },
-                ],
-            },
+            code: 6001,
+            name: 'OnlyOperator',
+            msg: 'Only Operator',
         },
         {
-            name: 'vault',
-            type: {
-                kind: 'struct',
-                fields: [
-                    {
-                        name: 'operator',
-                        type: 'publicKey',
-                    },
-                    {
-                        name: 'bump',
-                        type: 'u8',
-                    },
-                    {
-                        name: 'seed',
-                        type: 'string',
-                    },
-                    {
-                        name: 'reserve',
-                        type: {
-                            array: ['u128', 6],
-                        },
-                    },
-                ],
-            },
+            code: 6002,
+            name: 'InvalidAccountArgument',
+            msg: 'Invalid Account Argument',
         },
         {
-            name: 'repayVoucher',
-            type: {
-                kind: 'struct',
-                fields: [
-                    {
-                        name: 'discountPercentage',
-                        type: 'u16',
-                    },
-                    {
-                        name: 'maximumAmount',
-                        type: 'u32',
-                    },
-                    {
-                        name: 'startTime',
-                        type: 'i64',
-                    },
-                    {
-                        name: 'endTime',
-                        type: 'i64',
-                    },
-                    {
-                        name: 'nftMint',
-                        type: 'publicKey',
-                    },
-                    {
-                        name: 'authorator',
-                        type: 'publicKey',
-                    },
-                    {
-                        name: 'reserve',
-                        type: {
-                            array: ['u128', 6],
-                        },
-                    },
-                ],
-            },
+            code: 6003,
+            name: 'AccountNotInitialized',
+            msg: 'Account Not Initialized',
         },
-    ],
-    types: [
         {
-            name: 'AddRepayVoucherParams',
-            type: {
-                kind: 'struct',
-                fields: [
-                    {
-                        name: 'discountPercentage',
-                        type: 'u16',
-                    },
-                    {
-                        name: 'maximumAmount',
-                        type: 'u32',
-                    },
-                    {
-                        name: 'startTime',
-                        type: 'i64',
-                    },
-                    {
-                        name: 'endTime',
-                        type: 'i64',
-                    },
-                ],
-            },
+            code: 6004,
+            name: 'AuthoratorNotSigned',
+            msg: 'Authorator Not Signed',
         },
         {
-            name: 'MetadataParams',
-            type: {
-                kind: 'struct',
-                fields: [
-                    {
-                        name: 'name',
-                        type: 'string',
-                    },
-                    {
-                        name: 'symbol',
-                        type: 'string',
-                    },
-                    {
-                        name: 'uri',
-                        type: 'string',
-                    },
-                ],
-            },
+            code: 6005,
+            name: 'VaultNotSigned',
+            msg: 'Vault Not Signed',
         },
         {
-            name: 'VoucherNftError',
-            type: {
-                kind: 'enum',
-                variants: [
-                    {
-                        name: 'OnlyAdmin',
-                    },
-                    {
-                        name: 'OnlyOperator',
-                    },
-                    {
-                        name: 'InvalidAccountArgument',
-                    },
-                    {
-                        name: 'AccountNotInitialized',
-                    },
-                    {
-                        name: 'AuthoratorNotSigned',
-                    },
-                    {
-                        name: 'VaultNotSigned',
-                    },
-                    {
-                        name: 'InvalidDiscountPercentage',
-                    },
-                    {
-                        name: 'InvalidMaximumAmount',
-                    },
-                    {
-                        name: 'StartTimeAfterEndTime',
-                    },
-                    {
-                        name: 'StartTimeBeforeCurrentTime',
-                    },
-                    {
-                        name: 'InvalidNftMint',
-                    },
-                ],
-            },
+            code: 6006,
+            name: 'InvalidDiscountPercentage',
+            msg: 'Invalid Discount Percentage',
+        },
+        {
+            code: 6007,
+            name: 'InvalidMaximumAmount',
+            msg: 'Invalid Maximum Amount',
+        },
+        {
+            code: 6008,
+            name: 'StartTimeAfterEndTime',
+            msg: 'StartTime After EndTime',
+        },
+        {
+            code: 6009,
+            name: 'StartTimeBeforeCurrentTime',
+            msg: 'StartTime Before CurrentTime',
+        },
+        {
+            code: 6010,
+            name: 'InvalidNftMint',
+            msg: 'Invalid Nft Mint',
+        },
+        {
+            code: 6011,
+            name: 'VoucherNotStarted',
+            msg: 'Voucher Not Started',
+        },
+        {
+            code: 6012,
+            name: 'VoucherExpired',
+            msg: 'Voucher Expired',
+        },
+        {
+            code: 6013,
+            name: 'InvalidVoucherHolder',
+            msg: 'Invalid Voucher Holder',
+        },
+        {
+            code: 6014,
+            name: 'OnlyPendingAdmin',
+            msg: 'Only Pending Admin',
+        },
+        {
+            code: 6015,
+            name: 'VaultNotEmpty',
+            msg: 'Vault Not Empty',
+        },
+        {
+            code: 6016,
+            name: 'MathOverflow',
+            msg: 'Math Overflow',
+        },
+        {
+            code: 6017,
+            name: 'ProgramPaused',
+            msg: 'Program Paused',
+        },
+        {
+            code: 6018,
+            name: 'InvalidPermissions',
+            msg: 'Invalid Permissions',
+        },
+        {
+            code: 6019,
+            name: 'InvalidMerkleProof',
+            msg: 'Invalid Merkle Proof',
+        },
+        {
+            code: 6020,
+            name: 'ClaimWindowClosed',
+            msg: 'Claim Window Closed',
+        },
+        {
+            code: 6021,
+            name: 'ClaimWindowNotClosed',
+            msg: 'Claim Window Not Closed',
+        },
+        {
+            code: 6022,
+            name: 'InvalidTicketSignature',
+            msg: 'Invalid Ticket Signature',
+        },
+        {
+            code: 6023,
+            name: 'TicketExpired',
+            msg: 'Ticket Expired',
+        },
+        {
+            code: 6024,
+            name: 'VoucherAlreadyActive',
+            msg: 'Voucher Already Active',
+        },
+        {
+            code: 6025,
+            name: 'InvalidEndTime',
+            msg: 'Invalid End Time',
+        },
+        {
+            code: 6026,
+            name: 'VoucherNotClosable',
+            msg: 'Voucher Not Closable',
+        },
+        {
+            code: 6027,
+            name: 'VoucherRevoked',
+            msg: 'Voucher Revoked',
+        },
+        {
+            code: 6028,
+            name: 'RecipientNotSigned',
+            msg: 'Recipient Not Signed',
+        },
+        {
+            code: 6029,
+            name: 'InvalidRateReduction',
+            msg: 'Invalid Rate Reduction',
+        },
+        {
+            code: 6030,
+            name: 'InvalidVoucherDuration',
+            msg: 'Invalid Voucher Duration',
+        },
+        {
+            code: 6031,
+            name: 'InvalidCreditAmount',
+            msg: 'Invalid Credit Amount',
+        },
+        {
+            code: 6032,
+            name: 'InvalidCurrencyMint',
+            msg: 'Invalid Currency Mint',
+        },
+        {
+            code: 6033,
+            name: 'VaultCountNotTracked',
+            msg: 'Vault Count Not Tracked',
+        },
+        {
+            code: 6034,
+            name: 'VoucherAlreadyRegistered',
+            msg: 'Voucher Already Registered',
         },
     ],
 };
@@ -5,6 +5,9 @@ export class Constants {
     static readonly VAULT_SEED = 'VAULT';
     static readonly AUTHORATOR_SEED = 'AUTHORATOR';
     static readonly REPAY_VOUCHER_SEED = 'REPAY_VOUCHER';
+    static readonly INTEREST_RATE_VOUCHER_SEED = 'INTEREST_RATE_VOUCHER';
+    static readonly FIXED_CREDIT_VOUCHER_SEED = 'FIXED_CREDIT_VOUCHER';
+    static readonly OPERATOR_ROLE_SEED = 'OPERATOR_ROLE';
     static readonly VOUCHER_NFT_PROGRAM_ID_TESTNET = new PublicKey('83Y1RXET7F21aeyLaSSrGxwWrAP7jhXdDNwi1znMGU72');
     static readonly TOKEN_METADATA_PROGRAM = new PublicKey('metaXfaoQatFJP9xiuYRsKkHYgS5NqqcfxFbLGS5LdN');
 }
@@ -2,11 +2,15 @@ import { Program } from '@project-serum/anchor';
 import {
     AddVoucherRepayInstructionParams,
     AddVaultInstructionParams,
+    ApplyRepayDiscountInstructionParams,
+    GrantOperatorRoleInstructionParams,
     MintVoucherInstructionParams,
     VoucherNftType,
     OperatorAirdropToUserInstructionParams,
+    RedeemRepayVoucherInstructionParams,
+    RevokeOperatorRoleInstructionParams,
 } from './types';
-import { ComputeBudgetProgram } from '@solana/web3.js';
+import { ComputeBudgetProgram, PublicKey, TransactionInstruction } from '@solana/web3.js';
 
 export function addVaultIx(program: Program<VoucherNftType>, params: AddVaultInstructionParams) {
     return program.methods
@@ -19,6 +23,30 @@ export function addVaultIx(program: Program<VoucherNftType>, params: AddVaultIns
         .instruction();
 }
 
+export function grantOperatorRoleIx(program: Program<VoucherNftType>, params: GrantOperatorRoleInstructionParams) {
+    return program.methods
+        .grantOperatorRole(params.operator, params.permissions)
+        .accounts({
+            config: params.config,
+            vault: params.vault,
+            operatorRole: params.operatorRole,
+            admin: params.admin,
+        })
+        .instruction();
+}
+
+export function revokeOperatorRoleIx(program: Program<VoucherNftType>, params: RevokeOperatorRoleInstructionParams) {
+    return program.methods
+        .revokeOperatorRole(params.permissions)
+        .accounts({
+            config: params.config,
+            vault: params.vault,
+            operatorRole: params.operatorRole,
+            admin: params.admin,
+        })
+        .instruction();
+}
+
 export async function mintVoucherIx(program: Program<VoucherNftType>, params: MintVoucherInstructionParams) {
     return program.methods
         .mintVoucher({ name: params.params.name, symbol: params.params.symbol, uri: params.params.uri })
@@ -26,6 +54,7 @@ export async function mintVoucherIx(program: Program<VoucherNftType>, params: Mi
             config: params.config,
             vault: params.vault,
             operator: params.operator,
+            operatorRole: params.operatorRole,
             authorator: params.authorator,
             vaultTokenAccount: params.vaultTokenAccount,
             metadataAccount: params.metadataAccount,
@@ -46,14 +75,19 @@ export async function addRepayVoucherIx(program: Program<VoucherNftType>, params
             endTime: params.params.endTime,
             discountPercentage: params.params.discountPercentage,
             maximumAmount: params.params.maximumAmount,
+            maxUses: params.params.maxUses,
         })
         .accounts({
+            config: params.config,
             vault: params.vault,
             operator: params.operator,
+            operatorRole: params.operatorRole,
             mint: params.mint.publicKey,
             metadataAccount: params.metadataAccount,
             masterEdition: params.masterEdition,
             repayVoucher: params.repayVoucher,
+            interestRateVoucher: params.interestRateVoucher,
+            fixedCreditVoucher: params.fixedCreditVoucher,
             tokenMetadataProgram: params.tokenMetadataProgram,
         })
         .instruction();
@@ -66,17 +100,70 @@ export async function airdropToUserIx(
     return program.methods
         .operatorAirdrop()
         .accounts({
+            config: params.config,
             vault: params.vault,
             operator: params.operator,
+            operatorRole: params.operatorRole,
             user: params.user,
             mint: params.mint,
             masterEdition: params.masterEdition,
             vaultTokenAccount: params.vaultTokenAccount,
             userTokenAccount: params.userTokenAccount,
+            tokenMetadataProgram: params.tokenMetadataProgram,
+        })
+        .instruction();
+}
+
+export async function redeemRepayVoucherIx(
+    program: Program<VoucherNftType>,
+    params: RedeemRepayVoucherInstructionParams
+) {
+    return program.methods
+        .redeemRepayVoucher(params.amount)
+        .accounts({
+            config: params.config,
+            vault: params.vault,
+            holder: params.holder,
+            mint: params.mint,
+            holderTokenAccount: params.holderTokenAccount,
+            repayVoucher: params.repayVoucher,
+            metadataAccount: params.metadataAccount,
+            masterEdition: params.masterEdition,
+            tokenMetadataProgram: params.tokenMetadataProgram,
         })
         .instruction();
 }
 
+export async function applyRepayDiscountIx(
+    program: Program<VoucherNftType>,
+    params: ApplyRepayDiscountInstructionParams
+) {
+    return program.methods
+        .applyRepayDiscount({ amount: params.params.amount, consume: params.params.consume })
+        .accounts({
+            config: params.config,
+            vault: params.vault,
+            holder: params.holder,
+            mint: params.mint,
+            holderTokenAccount: params.holderTokenAccount,
+            repayVoucher: params.repayVoucher,
+            metadataAccount: params.metadataAccount,
+            masterEdition: params.masterEdition,
+            tokenMetadataProgram: params.tokenMetadataProgram,
+        })
+        .instruction();
+}
+
+// Mirrors `with_signer` of the Rust client, see client/src/instructions.rs
+export function withSigner(ix: TransactionInstruction, signer: PublicKey) {
+    ix.keys.forEach((key) => {
+        if (key.pubkey.equals(signer)) {
+            key.isSigner = true;
+        }
+    });
+    return ix;
+}
+
 export function modifyComputeUnitIx(units?: number) {
     return ComputeBudgetProgram.setComputeUnitLimit({
         units: units || 1000000,
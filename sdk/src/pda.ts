@@ -48,6 +48,17 @@ export class PDA {
         };
     };
 
+    operatorRole = (vault: PublicKey, operator: PublicKey): PDAInfo => {
+        const [pda, bump] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(Constants.OPERATOR_ROLE_SEED), vault.toBuffer(), operator.toBuffer()],
+            this.programId
+        );
+        return {
+            key: pda,
+            bump,
+        };
+    };
+
     repayVoucher = (mint: PublicKey): PDAInfo => {
         const [pda, bump] = anchor.web3.PublicKey.findProgramAddressSync(
             [Buffer.from(Constants.REPAY_VOUCHER_SEED), mint.toBuffer()],
@@ -59,6 +70,28 @@ export class PDA {
         };
     };
 
+    interestRateVoucher = (mint: PublicKey): PDAInfo => {
+        const [pda, bump] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(Constants.INTEREST_RATE_VOUCHER_SEED), mint.toBuffer()],
+            this.programId
+        );
+        return {
+            key: pda,
+            bump,
+        };
+    };
+
+    fixedCreditVoucher = (mint: PublicKey): PDAInfo => {
+        const [pda, bump] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(Constants.FIXED_CREDIT_VOUCHER_SEED), mint.toBuffer()],
+            this.programId
+        );
+        return {
+            key: pda,
+            bump,
+        };
+    };
+
     metadata = async (mint: anchor.web3.PublicKey): Promise<PDAInfo> => {
         const key = await Metadata.getPDA(mint);
         return {
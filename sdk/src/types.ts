@@ -65,6 +65,29 @@ export type AddVaultInstructionParams = {
     seed: string;
 };
 
+export enum OperatorPermission {
+    Minter = 1 << 0,
+    InformationManager = 1 << 1,
+    Airdropper = 1 << 2,
+}
+
+export type GrantOperatorRoleInstructionParams = {
+    config: PublicKey;
+    vault: PublicKey;
+    operatorRole: PublicKey;
+    admin: PublicKey;
+    operator: PublicKey;
+    permissions: number;
+};
+
+export type RevokeOperatorRoleInstructionParams = {
+    config: PublicKey;
+    vault: PublicKey;
+    operatorRole: PublicKey;
+    admin: PublicKey;
+    permissions: number;
+};
+
 export type MetadataParams = {
     name: string;
     symbol: string;
@@ -75,6 +98,7 @@ export type MintVoucherInstructionParams = {
     config: PublicKey;
     vault: PublicKey;
     operator: PublicKey;
+    operatorRole: PublicKey;
     authorator: PublicKey;
     vaultTokenAccount: PublicKey;
     mint: Keypair;
@@ -90,28 +114,67 @@ export type MintVoucherInstructionParams = {
 export type RepayVoucherInformationParams = {
     discountPercentage: number;
     maximumAmount: number;
+    maxUses: number;
     startTime: BN;
     endTime: BN;
 };
 
 export type AddVoucherRepayInstructionParams = {
+    config: PublicKey;
     vault: PublicKey;
     operator: PublicKey;
+    operatorRole: PublicKey;
     authorator: PublicKey;
     mint: Keypair;
     metadataAccount: PublicKey;
     masterEdition: PublicKey;
     repayVoucher: PublicKey;
+    interestRateVoucher: PublicKey;
+    fixedCreditVoucher: PublicKey;
     tokenMetadataProgram: PublicKey;
     params: RepayVoucherInformationParams;
 };
 
 export type OperatorAirdropToUserInstructionParams = {
+    config: PublicKey;
     vault: PublicKey;
     operator: PublicKey;
+    operatorRole: PublicKey;
     user: PublicKey;
     mint: PublicKey;
     masterEdition: PublicKey;
     vaultTokenAccount: PublicKey;
     userTokenAccount: PublicKey;
+    tokenMetadataProgram: PublicKey;
+};
+
+export type RedeemRepayVoucherInstructionParams = {
+    config: PublicKey;
+    vault: PublicKey;
+    holder: PublicKey;
+    mint: PublicKey;
+    holderTokenAccount: PublicKey;
+    repayVoucher: PublicKey;
+    metadataAccount: PublicKey;
+    masterEdition: PublicKey;
+    tokenMetadataProgram: PublicKey;
+    amount: BN;
+};
+
+export type ApplyRepayDiscountParams = {
+    amount: BN;
+    consume: boolean;
+};
+
+export type ApplyRepayDiscountInstructionParams = {
+    config: PublicKey;
+    vault: PublicKey;
+    holder: PublicKey;
+    mint: PublicKey;
+    holderTokenAccount: PublicKey;
+    repayVoucher: PublicKey;
+    metadataAccount: PublicKey;
+    masterEdition: PublicKey;
+    tokenMetadataProgram: PublicKey;
+    params: ApplyRepayDiscountParams;
 };
@@ -11,7 +11,16 @@ import {
 } from './types';
 import { getKeypairFromFile } from '@solana-developers/helpers';
 import { PDA } from './pda';
-import { addRepayVoucherIx, addVaultIx, airdropToUserIx, mintVoucherIx, modifyComputeUnitIx } from './instructions';
+import {
+    addRepayVoucherIx,
+    addVaultIx,
+    airdropToUserIx,
+    grantOperatorRoleIx,
+    mintVoucherIx,
+    modifyComputeUnitIx,
+    revokeOperatorRoleIx,
+    withSigner,
+} from './instructions';
 import { Keypair, PublicKey } from '@solana/web3.js';
 import { Constants } from './constants';
 
@@ -81,6 +90,49 @@ export class VoucherNftFixture {
         }
     }
 
+    async grantOperatorRole(seed: string, operator: PublicKey, permissions: number): Promise<string> {
+        try {
+            const { key: config } = this.pda.config();
+            const { key: vault } = this.pda.vault(seed);
+            const { key: operatorRole } = this.pda.operatorRole(vault, operator);
+            const grantOperatorRoleIns = await grantOperatorRoleIx(this.program, {
+                config,
+                vault,
+                operatorRole,
+                admin: this.provider.publicKey,
+                operator,
+                permissions,
+            });
+
+            const transaction = new anchor.web3.Transaction().add(grantOperatorRoleIns);
+            return await this.provider.sendAndConfirm(transaction);
+        } catch (error) {
+            this.verbose && console.error(error);
+            throw error;
+        }
+    }
+
+    async revokeOperatorRole(seed: string, operator: PublicKey, permissions: number): Promise<string> {
+        try {
+            const { key: config } = this.pda.config();
+            const { key: vault } = this.pda.vault(seed);
+            const { key: operatorRole } = this.pda.operatorRole(vault, operator);
+            const revokeOperatorRoleIns = await revokeOperatorRoleIx(this.program, {
+                config,
+                vault,
+                operatorRole,
+                admin: this.provider.publicKey,
+                permissions,
+            });
+
+            const transaction = new anchor.web3.Transaction().add(revokeOperatorRoleIns);
+            return await this.provider.sendAndConfirm(transaction);
+        } catch (error) {
+            this.verbose && console.error(error);
+            throw error;
+        }
+    }
+
     async mintVoucher(seed: string, operator: Keypair, mint: Keypair, params: MetadataParams): Promise<string> {
         try {
             const { collection: collectionMint } = await this.getConfigData();
@@ -90,12 +142,14 @@ export class VoucherNftFixture {
             const { key: masterEdition } = await this.pda.masterEdition(mint.publicKey);
             const { key: authorator } = this.pda.authorator();
             const { key: vault } = this.pda.vault(seed);
+            const { key: operatorRole } = this.pda.operatorRole(vault, operator.publicKey);
             const { key: config } = this.pda.config();
             const vaultTokenAccount = await token.getAssociatedTokenAddress(mint.publicKey, vault, true);
             const modifyUnitIns = modifyComputeUnitIx();
             const mintVoucherIns = await mintVoucherIx(this.program, {
                 config,
                 operator: operator.publicKey,
+                operatorRole,
                 authorator,
                 tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
                 vaultTokenAccount,
@@ -131,13 +185,17 @@ export class VoucherNftFixture {
             const { key: masterEdition } = await this.pda.masterEdition(mint.publicKey);
             const { key: authorator } = this.pda.authorator();
             const { key: vault } = this.pda.vault(seed);
+            const { key: operatorRole } = this.pda.operatorRole(vault, operator.publicKey);
             const { key: config } = this.pda.config();
             const { key: repayVoucher } = this.pda.repayVoucher(mint.publicKey);
+            const { key: interestRateVoucher } = this.pda.interestRateVoucher(mint.publicKey);
+            const { key: fixedCreditVoucher } = this.pda.fixedCreditVoucher(mint.publicKey);
             const vaultTokenAccount = await token.getAssociatedTokenAddress(mint.publicKey, vault, true);
             const modifyUnitIns = modifyComputeUnitIx();
             const mintVoucherIns = await mintVoucherIx(this.program, {
                 config,
                 operator: operator.publicKey,
+                operatorRole,
                 authorator,
                 tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
                 vaultTokenAccount,
@@ -151,14 +209,18 @@ export class VoucherNftFixture {
                 params: metadataParams,
             });
             const addRepayVoucherIns = await addRepayVoucherIx(this.program, {
+                config,
                 authorator,
                 masterEdition,
                 metadataAccount,
                 mint,
                 operator: operator.publicKey,
+                operatorRole,
                 tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
                 vault,
                 repayVoucher,
+                interestRateVoucher,
+                fixedCreditVoucher,
                 params: repayVoucherInformationParams,
             });
             const transaction = new anchor.web3.Transaction().add(modifyUnitIns, mintVoucherIns, addRepayVoucherIns);
@@ -169,23 +231,32 @@ export class VoucherNftFixture {
         }
     }
 
-    async operatorAirdrop(seed: string, operator: Keypair, mint: PublicKey, user: PublicKey) {
+    // `userSigner` must be given when the vault is soulbound
+    async operatorAirdrop(seed: string, operator: Keypair, mint: PublicKey, user: PublicKey, userSigner?: Keypair) {
         try {
+            const { key: config } = this.pda.config();
             const { key: vault } = this.pda.vault(seed);
+            const { key: operatorRole } = this.pda.operatorRole(vault, operator.publicKey);
             const { key: masterEdition } = await this.pda.masterEdition(mint);
             const userTokenAccount = await token.getAssociatedTokenAddress(mint, user, false);
             const vaultTokenAccount = await token.getAssociatedTokenAddress(mint, vault, true);
             const operatorAirdropIns = await airdropToUserIx(this.program, {
+                config,
                 mint: mint,
                 masterEdition,
                 operator: operator.publicKey,
+                operatorRole,
                 user,
                 userTokenAccount: userTokenAccount,
                 vault: vault,
                 vaultTokenAccount: vaultTokenAccount,
+                tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             });
+            if (userSigner) {
+                withSigner(operatorAirdropIns, userSigner.publicKey);
+            }
             const transaction = new anchor.web3.Transaction().add(operatorAirdropIns);
-            return await this.provider.sendAndConfirm(transaction, [operator]);
+            return await this.provider.sendAndConfirm(transaction, userSigner ? [operator, userSigner] : [operator]);
         } catch (error) {
             this.verbose && console.error(error);
             throw error;
@@ -222,6 +293,17 @@ export class VoucherNftFixture {
         }
     }
 
+    async getOperatorRoleData(seed: string, operator: PublicKey) {
+        const { key: vault } = this.pda.vault(seed);
+        const { key: operatorRole } = this.pda.operatorRole(vault, operator);
+        try {
+            return await this.program.account.operatorRole.fetch(operatorRole);
+        } catch (error) {
+            this.verbose && console.error(error);
+            throw error;
+        }
+    }
+
     async getRepayVoucherData(mint: PublicKey) {
         const { key: repayVoucher } = this.pda.repayVoucher(mint);
         try {
@@ -131,6 +131,12 @@ describe('mint-repay-voucher', () => {
             repayVoucherInformationParams.maximumAmount,
             'Repay voucher maximumAmount mismatch'
         );
+        assert.equal(repayVoucherData.maxUses, repayVoucherInformationParams.maxUses, 'Repay voucher maxUses mismatch');
+        assert.equal(
+            repayVoucherData.usesRemaining,
+            repayVoucherInformationParams.maxUses,
+            'Repay voucher usesRemaining mismatch'
+        );
         assert.equal(
             repayVoucherData.authorator.toBase58(),
             authorator.toBase58(),
@@ -147,7 +153,10 @@ describe('mint-repay-voucher', () => {
         const fakeMetadata = anchor.web3.Keypair.generate().publicKey;
         const mint = anchor.web3.Keypair.generate();
         const { key: vault } = fixture.pda.vault(vaultSeed);
+        const { key: operatorRole } = fixture.pda.operatorRole(vault, operator.publicKey);
         const { key: repayVoucher } = fixture.pda.repayVoucher(mint.publicKey);
+        const { key: interestRateVoucher } = fixture.pda.interestRateVoucher(mint.publicKey);
+        const { key: fixedCreditVoucher } = fixture.pda.fixedCreditVoucher(mint.publicKey);
         const { key: metadata } = await fixture.pda.metadata(mint.publicKey);
         const { key: masterEdition } = await fixture.pda.masterEdition(mint.publicKey);
         const { key: authorator } = await fixture.pda.authorator();
@@ -161,6 +170,7 @@ describe('mint-repay-voucher', () => {
             metadataAccount: metadata,
             mint,
             operator: operator.publicKey,
+            operatorRole,
             params: metadataParams,
             tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             collection,
@@ -170,14 +180,18 @@ describe('mint-repay-voucher', () => {
             vaultTokenAccount: vaultTokenAccount,
         });
         const addRepayVoucherIns = await addRepayVoucherIx(fixture.program, {
+            config,
             authorator,
             masterEdition,
             metadataAccount: fakeMetadata,
             mint,
             operator: operator.publicKey,
+            operatorRole,
             tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             vault,
             repayVoucher,
+            interestRateVoucher,
+            fixedCreditVoucher,
             params: repayVoucherInformationParams,
         });
         const transaction = new anchor.web3.Transaction().add(
@@ -198,22 +212,30 @@ describe('mint-repay-voucher', () => {
     it('FAILED AccountNotInitialized: MetadataAccount is not initialized', async () => {
         const mint = anchor.web3.Keypair.generate();
         await createNftMint(fixture.provider, mint, operator);
+        const { key: config } = fixture.pda.config();
         const { key: vault } = fixture.pda.vault(vaultSeed);
+        const { key: operatorRole } = fixture.pda.operatorRole(vault, operator.publicKey);
         const { key: repayVoucher } = fixture.pda.repayVoucher(mint.publicKey);
+        const { key: interestRateVoucher } = fixture.pda.interestRateVoucher(mint.publicKey);
+        const { key: fixedCreditVoucher } = fixture.pda.fixedCreditVoucher(mint.publicKey);
         const { key: metadata } = await fixture.pda.metadata(mint.publicKey);
         const { key: masterEdition } = await fixture.pda.masterEdition(mint.publicKey);
         const { key: authorator } = await fixture.pda.authorator();
         const repayVoucherInformationParams = await createRepayVoucherInformationParams();
         const modifyComputationUnit = modifyComputeUnitIx();
         const addRepayVoucherIns = await addRepayVoucherIx(fixture.program, {
+            config,
             authorator,
             masterEdition,
             metadataAccount: metadata,
             mint,
             operator: operator.publicKey,
+            operatorRole,
             tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             vault,
             repayVoucher,
+            interestRateVoucher,
+            fixedCreditVoucher,
             params: repayVoucherInformationParams,
         });
         const transaction = new anchor.web3.Transaction().add(modifyComputationUnit, addRepayVoucherIns);
@@ -235,7 +257,10 @@ describe('mint-repay-voucher', () => {
         const fakeMasterEdition = anchor.web3.Keypair.generate().publicKey;
         const mint = anchor.web3.Keypair.generate();
         const { key: vault } = fixture.pda.vault(vaultSeed);
+        const { key: operatorRole } = fixture.pda.operatorRole(vault, operator.publicKey);
         const { key: repayVoucher } = fixture.pda.repayVoucher(mint.publicKey);
+        const { key: interestRateVoucher } = fixture.pda.interestRateVoucher(mint.publicKey);
+        const { key: fixedCreditVoucher } = fixture.pda.fixedCreditVoucher(mint.publicKey);
         const { key: metadata } = await fixture.pda.metadata(mint.publicKey);
         const { key: masterEdition } = await fixture.pda.masterEdition(mint.publicKey);
         const { key: authorator } = await fixture.pda.authorator();
@@ -249,6 +274,7 @@ describe('mint-repay-voucher', () => {
             metadataAccount: metadata,
             mint,
             operator: operator.publicKey,
+            operatorRole,
             params: metadataParams,
             tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             vault,
@@ -258,14 +284,18 @@ describe('mint-repay-voucher', () => {
             vaultTokenAccount: vaultTokenAccount,
         });
         const addRepayVoucherIns = await addRepayVoucherIx(fixture.program, {
+            config,
             authorator,
             masterEdition: fakeMasterEdition,
             metadataAccount: metadata,
             mint,
             operator: operator.publicKey,
+            operatorRole,
             tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             vault,
             repayVoucher,
+            interestRateVoucher,
+            fixedCreditVoucher,
             params: repayVoucherInformationParams,
         });
         const transaction = new anchor.web3.Transaction().add(
@@ -288,22 +318,30 @@ describe('mint-repay-voucher', () => {
         await createNftMint(fixture.provider, mint, operator);
         await createMetadataV2(fixture.provider, mint, operator);
 
+        const { key: config } = fixture.pda.config();
         const { key: vault } = fixture.pda.vault(vaultSeed);
+        const { key: operatorRole } = fixture.pda.operatorRole(vault, operator.publicKey);
         const { key: repayVoucher } = fixture.pda.repayVoucher(mint.publicKey);
+        const { key: interestRateVoucher } = fixture.pda.interestRateVoucher(mint.publicKey);
+        const { key: fixedCreditVoucher } = fixture.pda.fixedCreditVoucher(mint.publicKey);
         const { key: metadata } = await fixture.pda.metadata(mint.publicKey);
         const { key: masterEdition } = await fixture.pda.masterEdition(mint.publicKey);
         const { key: authorator } = await fixture.pda.authorator();
         const repayVoucherInformationParams = await createRepayVoucherInformationParams();
         const modifyComputationUnit = modifyComputeUnitIx();
         const addRepayVoucherIns = await addRepayVoucherIx(fixture.program, {
+            config,
             authorator,
             masterEdition,
             metadataAccount: metadata,
             mint,
             operator: operator.publicKey,
+            operatorRole,
             tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             vault,
             repayVoucher,
+            interestRateVoucher,
+            fixedCreditVoucher,
             params: repayVoucherInformationParams,
         });
         const transaction = new anchor.web3.Transaction().add(modifyComputationUnit, addRepayVoucherIns);
@@ -323,22 +361,30 @@ describe('mint-repay-voucher', () => {
         await createMetadataV2(fixture.provider, mint, operator);
         await createMasterEdition(fixture.provider, mint, operator);
 
+        const { key: config } = fixture.pda.config();
         const { key: vault } = fixture.pda.vault(vaultSeed);
+        const { key: operatorRole } = fixture.pda.operatorRole(vault, operator.publicKey);
         const { key: repayVoucher } = fixture.pda.repayVoucher(mint.publicKey);
+        const { key: interestRateVoucher } = fixture.pda.interestRateVoucher(mint.publicKey);
+        const { key: fixedCreditVoucher } = fixture.pda.fixedCreditVoucher(mint.publicKey);
         const { key: metadata } = await fixture.pda.metadata(mint.publicKey);
         const { key: masterEdition } = await fixture.pda.masterEdition(mint.publicKey);
         const { key: authorator } = await fixture.pda.authorator();
         const repayVoucherInformationParams = await createRepayVoucherInformationParams();
         const modifyComputationUnit = modifyComputeUnitIx();
         const addRepayVoucherIns = await addRepayVoucherIx(fixture.program, {
+            config,
             authorator,
             masterEdition,
             metadataAccount: metadata,
             mint,
             operator: operator.publicKey,
+            operatorRole,
             tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             vault,
             repayVoucher,
+            interestRateVoucher,
+            fixedCreditVoucher,
             params: repayVoucherInformationParams,
         });
         const transaction = new anchor.web3.Transaction().add(modifyComputationUnit, addRepayVoucherIns);
@@ -358,22 +404,30 @@ describe('mint-repay-voucher', () => {
         await createMetadataV2(fixture.provider, mint, operator, operator.publicKey);
         await createMasterEdition(fixture.provider, mint, operator);
 
+        const { key: config } = fixture.pda.config();
         const { key: vault } = fixture.pda.vault(vaultSeed);
+        const { key: operatorRole } = fixture.pda.operatorRole(vault, operator.publicKey);
         const { key: metadata } = await fixture.pda.metadata(mint.publicKey);
         const { key: repayVoucher } = fixture.pda.repayVoucher(mint.publicKey);
+        const { key: interestRateVoucher } = fixture.pda.interestRateVoucher(mint.publicKey);
+        const { key: fixedCreditVoucher } = fixture.pda.fixedCreditVoucher(mint.publicKey);
         const { key: masterEdition } = await fixture.pda.masterEdition(mint.publicKey);
         const { key: authorator } = await fixture.pda.authorator();
         const repayVoucherInformationParams = await createRepayVoucherInformationParams();
         const modifyComputationUnit = modifyComputeUnitIx();
         const addRepayVoucherIns = await addRepayVoucherIx(fixture.program, {
+            config,
             authorator,
             masterEdition,
             metadataAccount: metadata,
             mint,
             operator: operator.publicKey,
+            operatorRole,
             tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             vault,
             repayVoucher,
+            interestRateVoucher,
+            fixedCreditVoucher,
             params: repayVoucherInformationParams,
         });
         const transaction = new anchor.web3.Transaction().add(modifyComputationUnit, addRepayVoucherIns);
@@ -397,9 +451,12 @@ describe('mint-repay-voucher', () => {
 
         const mint = anchor.web3.Keypair.generate();
         const { key: vault } = fixture.pda.vault(vaultSeed);
+        const { key: operatorRole } = fixture.pda.operatorRole(vault, operator.publicKey);
         const { key: vault2 } = fixture.pda.vault(vaultSeed2);
         const { key: metadata } = await fixture.pda.metadata(mint.publicKey);
         const { key: repayVoucher } = fixture.pda.repayVoucher(mint.publicKey);
+        const { key: interestRateVoucher } = fixture.pda.interestRateVoucher(mint.publicKey);
+        const { key: fixedCreditVoucher } = fixture.pda.fixedCreditVoucher(mint.publicKey);
         const { key: masterEdition } = await fixture.pda.masterEdition(mint.publicKey);
         const { key: authorator } = await fixture.pda.authorator();
         const vaultTokenAccount = await token.getAssociatedTokenAddress(mint.publicKey, vault, true);
@@ -407,6 +464,7 @@ describe('mint-repay-voucher', () => {
         const mintVoucherIns = await mintVoucherIx(fixture.program, {
             config,
             operator: operator.publicKey,
+            operatorRole,
             authorator,
             tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             vaultTokenAccount,
@@ -421,14 +479,18 @@ describe('mint-repay-voucher', () => {
         });
         const repayVoucherInformationParams = await createRepayVoucherInformationParams();
         const addRepayVoucherIns = await addRepayVoucherIx(fixture.program, {
+            config,
             authorator,
             masterEdition,
             metadataAccount: metadata,
             mint,
             operator: operator.publicKey,
+            operatorRole,
             tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             vault: vault2,
             repayVoucher,
+            interestRateVoucher,
+            fixedCreditVoucher,
             params: repayVoucherInformationParams,
         });
 
@@ -450,21 +512,29 @@ describe('mint-repay-voucher', () => {
         await createMetadataV2(fixture.provider, mint, operator, authorator);
         await createMasterEdition(fixture.provider, mint, operator);
 
+        const { key: config } = fixture.pda.config();
         const { key: vault } = fixture.pda.vault(vaultSeed);
+        const { key: operatorRole } = fixture.pda.operatorRole(vault, operator.publicKey);
         const { key: repayVoucher } = fixture.pda.repayVoucher(mint.publicKey);
+        const { key: interestRateVoucher } = fixture.pda.interestRateVoucher(mint.publicKey);
+        const { key: fixedCreditVoucher } = fixture.pda.fixedCreditVoucher(mint.publicKey);
         const { key: metadata } = await fixture.pda.metadata(mint.publicKey);
         const { key: masterEdition } = await fixture.pda.masterEdition(mint.publicKey);
         const repayVoucherInformationParams = await createRepayVoucherInformationParams();
         const modifyComputationUnit = modifyComputeUnitIx();
         const addRepayVoucherIns = await addRepayVoucherIx(fixture.program, {
+            config,
             authorator,
             masterEdition,
             metadataAccount: metadata,
             mint,
             operator: operator.publicKey,
+            operatorRole,
             tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             vault,
             repayVoucher,
+            interestRateVoucher,
+            fixedCreditVoucher,
             params: repayVoucherInformationParams,
         });
         const transaction = new anchor.web3.Transaction().add(modifyComputationUnit, addRepayVoucherIns);
@@ -537,6 +607,7 @@ describe('mint-repay-voucher', () => {
             startTime: new BN(currentTime + 100),
             endTime: new BN(currentTime + 1000),
             maximumAmount: 1000,
+            maxUses: 1,
         };
     }
 });
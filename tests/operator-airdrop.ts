@@ -8,6 +8,7 @@ import { Metadata } from '@renec-foundation/mpl-token-metadata';
 import { Keypair, SendTransactionError } from '@solana/web3.js';
 import { BN } from '@project-serum/anchor';
 import { airdropToUserIx } from '../sdk/src/instructions';
+import { Constants } from '../sdk/src/constants';
 import { createNftMint } from './token-utils';
 
 describe('operator-airdrop', () => {
@@ -155,18 +156,23 @@ describe('operator-airdrop', () => {
     it('FAILED InvalidAccountArgument:  Airdrop failed because of wrong master edition address', async () => {
         const user = anchor.web3.Keypair.generate();
         const userTokenAccount = await token.getAssociatedTokenAddress(mint.publicKey, user.publicKey, false);
+        const { key: config } = fixture.pda.config();
         const { key: vault } = fixture.pda.vault(vaultSeed);
+        const { key: operatorRole } = fixture.pda.operatorRole(vault, operator.publicKey);
         const vaultTokenAccount = await token.getAssociatedTokenAddress(mint.publicKey, vault, true);
         const fakeMasterEdition = anchor.web3.Keypair.generate();
         try {
             const airdropIns = await airdropToUserIx(fixture.program, {
+                config,
                 masterEdition: fakeMasterEdition.publicKey,
                 mint: mint.publicKey,
                 operator: operator.publicKey,
+                operatorRole,
                 user: user.publicKey,
                 userTokenAccount: userTokenAccount,
                 vault: vault,
                 vaultTokenAccount: vaultTokenAccount,
+                tokenMetadataProgram: Constants.TOKEN_METADATA_PROGRAM,
             });
             const transaction = new anchor.web3.Transaction().add(airdropIns);
             await fixture.provider.sendAndConfirm(transaction, [operator]);
@@ -219,6 +225,7 @@ describe('operator-airdrop', () => {
             startTime: new BN(currentTime + 100),
             endTime: new BN(currentTime + 1000),
             maximumAmount: 1000,
+            maxUses: 1,
         };
     }
 });
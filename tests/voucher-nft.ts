@@ -2,7 +2,7 @@ import * as anchor from '@project-serum/anchor';
 import * as assert from 'assert';
 import { VoucherNftFixture, VoucherNftFixtureBuilder } from '../sdk/src/voucher-nft-fixture';
 import { SendTransactionError } from '@solana/web3.js';
-import { MetadataParams, NetworkType } from '../sdk/src/types';
+import { MetadataParams, NetworkType, OperatorPermission } from '../sdk/src/types';
 import { airdrop } from '../sdk/src/utils';
 import { addVaultIx } from '../sdk/src/instructions';
 
@@ -87,4 +87,27 @@ describe('voucher-nft', () => {
         assert.equal(vaultData.operator.toBase58(), operator.publicKey.toBase58());
         assert.equal(vaultData.bump, bump, 'Bump mismatch');
     });
+
+    it('Grant and revoke operator role success', async () => {
+        const vaultSeed = 'VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV';
+        const minter = anchor.web3.Keypair.generate();
+        const tx = await fixture.grantOperatorRole(
+            vaultSeed,
+            minter.publicKey,
+            OperatorPermission.Minter | OperatorPermission.Airdropper
+        );
+        console.log('Grant operator role success at ', tx);
+        const { key: vault } = fixture.pda.vault(vaultSeed);
+        const { bump } = fixture.pda.operatorRole(vault, minter.publicKey);
+
+        const operatorRoleData = await fixture.getOperatorRoleData(vaultSeed, minter.publicKey);
+        assert.equal(operatorRoleData.vault.toBase58(), vault.toBase58());
+        assert.equal(operatorRoleData.operator.toBase58(), minter.publicKey.toBase58());
+        assert.equal(operatorRoleData.permissions, OperatorPermission.Minter | OperatorPermission.Airdropper);
+        assert.equal(operatorRoleData.bump, bump, 'Bump mismatch');
+
+        await fixture.revokeOperatorRole(vaultSeed, minter.publicKey, OperatorPermission.Airdropper);
+        const revokedRoleData = await fixture.getOperatorRoleData(vaultSeed, minter.publicKey);
+        assert.equal(revokedRoleData.permissions, OperatorPermission.Minter);
+    });
 });